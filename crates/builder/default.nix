@@ -15,6 +15,13 @@
             ZULIP_LOGO
             ;
 
+          # from the Font Awesome source in nixpkgs, which is locked along with nixpkgs
+          DISCORD_LOGO = "${pkgs.font-awesome.src}/svgs/brands/discord.svg";
+          GLOBE_ICON = "${pkgs.font-awesome.src}/svgs/solid/globe.svg";
+          MASTODON_LOGO = "${pkgs.font-awesome.src}/svgs/brands/mastodon.svg";
+          RSS_ICON = "${pkgs.font-awesome.src}/svgs/solid/rss.svg";
+          TWITCH_LOGO = "${pkgs.font-awesome.src}/svgs/brands/twitch.svg";
+
           VOLLKORN = "${pkgs.vollkorn}/share/fonts/truetype/Vollkorn-Regular.ttf";
           TAILWINDCSS = lib.getExe pkgs.tailwindcss;
           CALENDAR_SNIPPET_JS = builtins.readFile ./snippet.js |> pkgs.writeText "snippet.js";
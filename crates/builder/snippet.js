@@ -30,7 +30,16 @@ function initCalendar({ events, displayEventTime, selectors }) {
         },
       },
       events,
-      eventContent: ({ event }) => ({ html: event.extendedProps.eventContent }),
+      eventContent: ({ event, timeText }) => {
+        const containerElm = document.createElement("div");
+        containerElm.innerHTML = event.extendedProps.eventContent;
+
+        containerElm.querySelectorAll("[data-local-time]").forEach((elm) => {
+          elm.textContent = timeText;
+        });
+
+        return { domNodes: [...containerElm.childNodes] };
+      },
       height: "auto",
       contentHeight: "auto",
       eventMinHeight: 40,
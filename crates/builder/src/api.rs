@@ -0,0 +1,171 @@
+use chrono::DateTime;
+use csscolorparser::Color;
+use maud::Render;
+use serde::Serialize;
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{
+    mob::{self, Mob, Participant, Person},
+    url::Url,
+};
+
+/// Incremented on every breaking change to the documents' structure
+const VERSION: u32 = 2;
+
+#[derive(Serialize)]
+struct MobsDocument<'a> {
+    version: u32,
+    mobs: Vec<ApiMob<'a>>,
+}
+
+#[derive(Serialize)]
+struct MobDocument<'a> {
+    version: u32,
+    mob: ApiMob<'a>,
+}
+
+#[derive(Serialize)]
+struct ApiMob<'a> {
+    id: String,
+    title: String,
+    subtitle: Option<String>,
+    participants: Vec<&'a Person>,
+    status: ApiStatus,
+    links: Vec<Url>,
+    background_color: &'a Color,
+    text_color: &'a Color,
+    schedule: Vec<ApiRecurringSession>,
+    freeform_copy: &'a str,
+    tags: &'a [mob::Tag],
+}
+
+#[derive(Serialize)]
+struct ApiStatus {
+    variant: String,
+    description: String,
+    /// The status' content, rendered as HTML
+    text: Option<String>,
+    renamed_to: Option<String>,
+}
+
+#[derive(Serialize)]
+struct ApiRecurringSession {
+    /// `None` for a single session
+    rrule: Option<String>,
+    timezone: String,
+    /// Local date and time of the first session, in the schedule's timezone
+    start: String,
+    duration_minutes: i64,
+    /// Local dates and times of sessions in addition to the recurrence
+    additional: Vec<String>,
+    /// Local dates and times of sessions of the recurrence that are cancelled
+    cancelled: Vec<String>,
+}
+
+impl<'a> From<&'a Mob> for ApiMob<'a> {
+    fn from(mob: &'a Mob) -> Self {
+        let participants = mob
+            .participants()
+            .iter()
+            .filter_map(|participant| match participant {
+                Participant::Hidden => None,
+                Participant::Public(person) => Some(person),
+            })
+            .collect();
+
+        let schedule = mob
+            .schedule()
+            .iter()
+            .map(|recurring_session| {
+                let start = recurring_session.start();
+                let local = |date_time: &DateTime<rrule::Tz>| {
+                    date_time.naive_local().format("%FT%R").to_string()
+                };
+
+                ApiRecurringSession {
+                    rrule: recurring_session
+                        .frequency()
+                        .as_ref()
+                        .map(ToString::to_string),
+                    timezone: start.timezone().name().to_owned(),
+                    start: local(&start),
+                    duration_minutes: recurring_session.duration().num_minutes(),
+                    additional: recurring_session
+                        .additional_starts()
+                        .iter()
+                        .map(local)
+                        .collect(),
+                    cancelled: recurring_session
+                        .cancelled_starts()
+                        .iter()
+                        .map(local)
+                        .collect(),
+                }
+            })
+            .collect();
+
+        Self {
+            id: mob.id().to_string(),
+            title: mob.title().to_string(),
+            subtitle: mob.subtitle().as_ref().map(ToString::to_string),
+            participants,
+            status: mob.status().into(),
+            links: mob.links().iter().map(mob::Link::url).collect(),
+            background_color: mob.background_color(),
+            text_color: mob.text_color(),
+            schedule,
+            freeform_copy: mob.freeform_copy_markdown().as_str(),
+            tags: mob.tags(),
+        }
+    }
+}
+
+impl From<&mob::Status> for ApiStatus {
+    fn from(status: &mob::Status) -> Self {
+        let renamed_to = match status {
+            mob::Status::Renamed(id) => Some(id.to_string()),
+            _ => None,
+        };
+
+        let variant = status.as_ref();
+
+        Self {
+            variant: variant.to_owned(),
+            description: mob::Status::description(variant)
+                .to_string()
+                .trim()
+                .to_owned(),
+            text: status.content().map(|text| text.render().into_string()),
+            renamed_to,
+        }
+    }
+}
+
+pub(crate) fn all(mobs: &[Mob]) -> Vec<FileSpec> {
+    let index = MobsDocument {
+        version: VERSION,
+        mobs: mobs.iter().map(ApiMob::from).collect(),
+    };
+
+    let index = FileSpec::new(
+        "/api/mobs.json",
+        BytesSource::new(serde_json::to_vec(&index).unwrap(), None),
+    )
+    .with_label("api");
+
+    mobs.iter()
+        .map(|mob| {
+            let document = MobDocument {
+                version: VERSION,
+                mob: mob.into(),
+            };
+
+            FileSpec::new(
+                format!("/api/mobs/{}.json", mob.id()),
+                BytesSource::new(serde_json::to_vec(&document).unwrap(), None),
+            )
+            .with_label("api")
+        })
+        .chain([index])
+        .collect()
+}
@@ -0,0 +1,171 @@
+use std::fmt::{self, Display};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+
+use crate::xml::escape;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Feed {
+    id: String,
+    title: String,
+    author: String,
+    link: String,
+    entries: Vec<Entry>,
+}
+
+impl Feed {
+    /// The feed's own URL serves as its id
+    pub(crate) fn new(
+        id: String,
+        title: String,
+        author: String,
+        link: String,
+        entries: Vec<Entry>,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            author,
+            link,
+            entries,
+        }
+    }
+
+    fn updated(&self) -> Option<DateTime<Utc>> {
+        self.entries.iter().map(|entry| entry.updated).max()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Entry {
+    id: String,
+    title: String,
+    updated: DateTime<Utc>,
+    link: String,
+    summary: Option<String>,
+    content_html: String,
+}
+
+impl Entry {
+    pub(crate) fn new(
+        id: String,
+        title: String,
+        updated: DateTime<Utc>,
+        link: String,
+        summary: Option<String>,
+        content_html: String,
+    ) -> Self {
+        Self {
+            id,
+            title,
+            updated,
+            link,
+            summary,
+            content_html,
+        }
+    }
+}
+
+impl Display for Feed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, r#"<?xml version="1.0" encoding="utf-8"?>"#)?;
+        writeln!(f, r#"<feed xmlns="http://www.w3.org/2005/Atom">"#)?;
+        writeln!(f, "  <id>{}</id>", escape(&self.id))?;
+        writeln!(f, "  <title>{}</title>", escape(&self.title))?;
+
+        // a feed without entries has not been updated since the epoch
+        let updated = self.updated().unwrap_or_default();
+        writeln!(f, "  <updated>{}</updated>", timestamp(updated))?;
+
+        writeln!(f, r#"  <link rel="self" href="{}"/>"#, escape(&self.id))?;
+        writeln!(f, r#"  <link href="{}"/>"#, escape(&self.link))?;
+        writeln!(
+            f,
+            "  <author><name>{}</name></author>",
+            escape(&self.author)
+        )?;
+
+        for entry in &self.entries {
+            write!(f, "{entry}")?;
+        }
+
+        writeln!(f, "</feed>")
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  <entry>")?;
+        writeln!(f, "    <id>{}</id>", escape(&self.id))?;
+        writeln!(f, "    <title>{}</title>", escape(&self.title))?;
+        writeln!(f, "    <updated>{}</updated>", timestamp(self.updated))?;
+        writeln!(f, r#"    <link href="{}"/>"#, escape(&self.link))?;
+
+        if let Some(summary) = &self.summary {
+            writeln!(f, "    <summary>{}</summary>", escape(summary))?;
+        }
+
+        writeln!(
+            f,
+            r#"    <content type="html">{}</content>"#,
+            escape(&self.content_html)
+        )?;
+        writeln!(f, "  </entry>")
+    }
+}
+
+fn timestamp(date_time: DateTime<Utc>) -> String {
+    date_time.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{TimeZone, Utc};
+
+    use super::{Entry, Feed};
+
+    #[test]
+    fn feed_display() {
+        let entry = Entry::new(
+            "https://mobusoperandi.com/mobs/bass.html".to_owned(),
+            "Bass (Open)".to_owned(),
+            Utc.with_ymd_and_hms(2023, 1, 5, 17, 0, 0).unwrap(),
+            "https://mobusoperandi.com/mobs/bass.html".to_owned(),
+            Some("Rock & roll".to_owned()),
+            "<p>We play</p>".to_owned(),
+        );
+
+        let actual = Feed::new(
+            "https://mobusoperandi.com/feed.atom".to_owned(),
+            "Mobs".to_owned(),
+            "Mobus Operandi".to_owned(),
+            "https://mobusoperandi.com/".to_owned(),
+            vec![entry],
+        )
+        .to_string();
+
+        let expected = [
+            r#"<?xml version="1.0" encoding="utf-8"?>"#,
+            r#"<feed xmlns="http://www.w3.org/2005/Atom">"#,
+            "  <id>https://mobusoperandi.com/feed.atom</id>",
+            "  <title>Mobs</title>",
+            "  <updated>2023-01-05T17:00:00Z</updated>",
+            r#"  <link rel="self" href="https://mobusoperandi.com/feed.atom"/>"#,
+            r#"  <link href="https://mobusoperandi.com/"/>"#,
+            "  <author><name>Mobus Operandi</name></author>",
+            "  <entry>",
+            "    <id>https://mobusoperandi.com/mobs/bass.html</id>",
+            "    <title>Bass (Open)</title>",
+            "    <updated>2023-01-05T17:00:00Z</updated>",
+            r#"    <link href="https://mobusoperandi.com/mobs/bass.html"/>"#,
+            "    <summary>Rock &amp; roll</summary>",
+            r#"    <content type="html">&lt;p&gt;We play&lt;/p&gt;</content>"#,
+            "  </entry>",
+            "</feed>",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(actual, expected);
+    }
+}
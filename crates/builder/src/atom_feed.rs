@@ -0,0 +1,70 @@
+use maud::html;
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{
+    atom,
+    constants::NAME,
+    mob::{Mob, Status},
+    url::Url,
+};
+
+pub(crate) const PATH: &str = "/feed.atom";
+
+/// One entry per mob, so that feed readers show a mob again when its status or description changes
+pub(crate) fn file_spec(mobs: &[Mob], base_url: &Url) -> FileSpec {
+    let mut entries = mobs
+        .iter()
+        .filter(|mob| !matches!(mob.status(), Status::Renamed(_)))
+        .collect::<Vec<_>>();
+
+    entries.sort_by_key(|mob| std::cmp::Reverse(*mob.updated()));
+
+    let entries = entries
+        .into_iter()
+        .map(|mob| entry(mob, base_url))
+        .collect();
+
+    let feed = atom::Feed::new(
+        base_url.join(PATH).unwrap().as_str().to_owned(),
+        format!("Mobs; {NAME}"),
+        NAME.to_owned(),
+        base_url.join("/").unwrap().as_str().to_owned(),
+        entries,
+    );
+
+    FileSpec::new(PATH, BytesSource::new(feed.to_string().into_bytes(), None)).with_label("feed")
+}
+
+fn entry(mob: &Mob, base_url: &Url) -> atom::Entry {
+    let url = base_url
+        .join(&format!("/mobs/{}.html", mob.id()))
+        .unwrap()
+        .as_str()
+        .to_owned();
+    let status = mob.status().as_ref();
+
+    let content = html! {
+        p {
+            "Status: " (status)
+
+            @if let Some(indicator) = mob.status().indicator() {
+                " " (indicator)
+            }
+        }
+
+        @if let Some(status_content) = mob.status().content() {
+            (status_content)
+        }
+
+        (mob.freeform_copy_markdown())
+    };
+
+    atom::Entry::new(
+        url.clone(),
+        format!("{} ({status})", mob.title()),
+        *mob.updated(),
+        url,
+        mob.subtitle().as_ref().map(ToString::to_string),
+        content.into_string(),
+    )
+}
@@ -0,0 +1,124 @@
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{
+    constants::{DOMAIN, NAME},
+    ical,
+    mob::{self, Mob},
+};
+
+pub(crate) fn all(mobs: &[Mob]) -> Vec<FileSpec> {
+    mobs.iter()
+        .filter_map(mob_calendar)
+        .chain([site_calendar(mobs)])
+        .collect()
+}
+
+fn site_calendar(mobs: &[Mob]) -> FileSpec {
+    let events = mobs
+        .iter()
+        .filter(|mob| mob.is_active())
+        .flat_map(mob_events)
+        .collect();
+
+    let calendar = ical::Calendar::new(NAME.to_owned(), events);
+    let bytes = calendar.to_string().into_bytes();
+
+    FileSpec::new("/calendar.ics", BytesSource::new(bytes, None)).with_label("calendar")
+}
+
+fn mob_calendar(mob: &Mob) -> Option<FileSpec> {
+    let events = match mob.status() {
+        mob::Status::Renamed(_) => return None,
+        // an empty calendar removes the sessions from subscribers' calendars
+        mob::Status::Terminated(_) => vec![],
+        _ => mob_events(mob),
+    };
+
+    let calendar = ical::Calendar::new(format!("{}; {NAME}", mob.title()), events);
+    let bytes = calendar.to_string().into_bytes();
+
+    Some(
+        FileSpec::new(
+            format!("/mobs/{}.ics", mob.id()),
+            BytesSource::new(bytes, None),
+        )
+        .with_label("calendar"),
+    )
+}
+
+// UIDs are derived from the mob id and the session's position in its schedule, rather than from its time,
+// so that calendar clients update the events in place when a session is rescheduled.
+// Their timestamp is when the mob file was updated, so that a rebuild of the same mob files writes the same feeds
+fn mob_events(mob: &Mob) -> Vec<ical::Event> {
+    mob.schedule()
+        .iter()
+        .enumerate()
+        .map(|(index, recurring_session)| {
+            ical::Event::new(
+                format!("{index}.{}@{DOMAIN}", mob.id()),
+                *mob.updated(),
+                recurring_session.start(),
+                ical::Recurrence::new(
+                    recurring_session
+                        .frequency()
+                        .as_ref()
+                        .map(ToString::to_string),
+                    recurring_session.additional_starts().clone(),
+                    recurring_session.cancelled_starts().clone(),
+                ),
+                recurring_session.duration(),
+                mob.title().to_string(),
+                mob.freeform_copy_markdown().as_str().to_owned(),
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, rc::Rc};
+
+    use camino::{Utf8Path, Utf8PathBuf};
+    use ssg_child::{file_success::FileOutcome, generate_static_site};
+
+    use crate::{diagnostic::Severity, mob};
+
+    #[tokio::test]
+    async fn rebuild_writes_nothing() {
+        let root = Utf8Path::new(env!("CARGO_MANIFEST_DIR")).join("../..");
+        let (mobs, _) = mob::get_all(
+            &root.join("mobs"),
+            &root.join("people.yaml"),
+            Severity::Warning,
+            None,
+        )
+        .unwrap();
+
+        let output_dir = tempfile::tempdir().unwrap();
+        let output_dir = Utf8PathBuf::try_from(output_dir.path().to_owned()).unwrap();
+
+        let mut runs = vec![];
+
+        for _ in 0..2 {
+            let outcomes = Rc::new(RefCell::new(vec![]));
+            let mut generation_task = generate_static_site(output_dir.clone(), super::all(&mobs));
+
+            generation_task.set_file_result_fn({
+                let outcomes = outcomes.clone();
+                move |result| {
+                    outcomes
+                        .borrow_mut()
+                        .push(result.as_ref().unwrap().outcome());
+                }
+            });
+
+            generation_task.await.unwrap();
+            runs.push(outcomes.take());
+        }
+
+        assert!(runs[0].contains(&FileOutcome::Written));
+        assert!(runs[1]
+            .iter()
+            .all(|outcome| *outcome == FileOutcome::Unchanged));
+    }
+}
@@ -2,10 +2,16 @@ pub(crate) mod add_page;
 mod calendar;
 pub(crate) mod home_page;
 pub(crate) mod mob_page;
+pub(crate) mod mobs_page;
 pub(crate) mod page_base;
+pub(crate) mod person_page;
 pub(crate) mod redirect_page;
 pub(crate) mod schema;
+pub(crate) mod tag_page;
+pub(crate) mod tags_page;
+pub(crate) mod upcoming_sessions;
 
 pub(crate) use calendar::Calendar;
 pub(crate) use calendar::CalendarEvent;
 pub(crate) use page_base::PageBase;
+pub(crate) use upcoming_sessions::UpcomingSessions;
@@ -1,22 +1,36 @@
 use maud::{html, Render};
+use serde_json::json;
 
 use crate::components;
 use crate::constants::{DEFAULT_BRANCH, GITHUB_PULL_REQUESTS_URL, MOBS_DIR, NAME, REPO_URL};
+use crate::json_schema;
+use crate::relative_path::RelativePathBuf;
 use crate::style::{PROSE_CLASSES, VERTICAL_GAP_CLASS};
 
 use super::schema::type_::Type;
 use super::PageBase;
 
+const YAML_LANGUAGE_SERVER_URL: &str = "https://github.com/redhat-developer/yaml-language-server";
+
 pub(crate) struct AddPage {
     internal_types: Vec<Type>,
     base: PageBase,
+    json_schema_path: RelativePathBuf,
+    yaml_custom_tags: Vec<String>,
 }
 
 impl AddPage {
-    pub(crate) fn new(internal_types: Vec<Type>, base: PageBase) -> Self {
+    pub(crate) fn new(
+        internal_types: Vec<Type>,
+        base: PageBase,
+        json_schema_path: RelativePathBuf,
+        yaml_custom_tags: Vec<String>,
+    ) -> Self {
         Self {
             internal_types,
             base,
+            json_schema_path,
+            yaml_custom_tags,
         }
     }
 }
@@ -32,6 +46,10 @@ impl Render for AddPage {
             .push(DEFAULT_BRANCH)
             .push(MOBS_DIR);
 
+        let yaml_custom_tags_setting =
+            serde_json::to_string_pretty(&json!({ "yaml.customTags": self.yaml_custom_tags }))
+                .unwrap();
+
         let content = html! {
             div class=(*PROSE_CLASSES) {
                 h1 { "Add a mob" }
@@ -49,6 +67,16 @@ impl Render for AddPage {
                     a href=(GITHUB_PULL_REQUESTS_URL) { "pull request" }
                     " that adds a mob file."
                 }
+                p {
+                    "The format of mob files is also available as a "
+                    a href=(self.json_schema_path) { "JSON Schema" }
+                    ". For completion and validation in editors that use "
+                    a href=(YAML_LANGUAGE_SERVER_URL) { "yaml-language-server" }
+                    ", begin the mob file with:"
+                }
+                pre { code { "# yaml-language-server: $schema=" (json_schema::url()) } }
+                p { "and register the tags it uses in the editor's settings:" }
+                pre { code { (yaml_custom_tags_setting) } }
             }
             ol class=(classes!("flex", "flex-col", VERTICAL_GAP_CLASS)) {
                 @for type_ in &self.internal_types {
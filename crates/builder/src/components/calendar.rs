@@ -1,12 +1,12 @@
-use chrono::Duration;
+use chrono::{DateTime, Datelike, Duration, NaiveTime, Utc};
 use csscolorparser::Color;
+use itertools::Itertools;
 use maud::{html, Markup, PreEscaped, Render};
-use rrule::RRuleSet;
-use serde::{Serialize, Serializer};
+use serde::{ser::Error, Serialize, Serializer};
 use serde_json::json;
 
 use crate::html::css_class;
-use crate::mob;
+use crate::mob::{self, RecurringSession};
 use crate::relative_path::RelativePathBuf;
 use crate::style::{BUTTON_CLASSES, BUTTON_GAP, TEXT_COLOR};
 
@@ -39,7 +39,8 @@ impl Calendar {
 #[derive(Serialize, Clone, Debug)]
 #[serde(rename_all = "camelCase")]
 pub(crate) struct CalendarEvent {
-    rrule: RRuleSet,
+    #[serde(rename = "rrule", serialize_with = "serialize_occurrences")]
+    occurrences: Vec<DateTime<Utc>>,
     #[serde(serialize_with = "serialize_duration")]
     duration: Duration,
     event_content: String,
@@ -49,15 +50,29 @@ pub(crate) struct CalendarEvent {
 
 impl CalendarEvent {
     pub(crate) fn new(
-        rrule: RRuleSet,
-        duration: Duration,
+        recurring_session: &RecurringSession,
         event_content: String,
         background_color: Color,
         text_color: Color,
     ) -> Self {
+        // by week, so that the occurrences are the same for builds in the same week
+        let today = Utc::now().date_naive();
+        let week_start = (today - Duration::days(today.weekday().num_days_from_monday().into()))
+            .and_time(NaiveTime::MIN)
+            .and_utc();
+
+        let occurrences = recurring_session
+            .occurrences(
+                week_start - Duration::weeks(WEEKS_BEFORE),
+                week_start + Duration::weeks(WEEKS_AFTER),
+            )
+            .into_iter()
+            .map(|occurrence| occurrence.with_timezone(&Utc))
+            .collect();
+
         Self {
-            rrule,
-            duration,
+            occurrences,
+            duration: recurring_session.duration(),
             event_content,
             background_color,
             text_color,
@@ -65,6 +80,32 @@ impl CalendarEvent {
     }
 }
 
+// FullCalendar places recurring occurrences wrongly in timezones with a non-zero offset
+// (https://github.com/fullcalendar/fullcalendar/issues/6815), and generates none for rules
+// with a TZID and without an UNTIL (https://github.com/fullcalendar/fullcalendar/issues/6834).
+// So instead of the rule, the occurrences within a window around the week of the build are expanded
+// in the schedule's own timezone and handed over in UTC.
+// The calendar runs out of sessions unless the website is rebuilt, which CI does weekly, at the start of the week.
+const WEEKS_BEFORE: i64 = 4;
+const WEEKS_AFTER: i64 = 12;
+
+fn serialize_occurrences<S>(occurrences: &[DateTime<Utc>], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let format = |date_time: &DateTime<Utc>| date_time.format("%Y%m%dT%H%M%SZ").to_string();
+
+    let first = occurrences
+        .first()
+        .ok_or_else(|| S::Error::custom("no occurrences"))?;
+
+    serializer.serialize_str(&format!(
+        "DTSTART:{}\nRDATE:{}",
+        format(first),
+        occurrences.iter().map(format).join(",")
+    ))
+}
+
 fn serialize_duration<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -112,8 +153,14 @@ impl Render for Calendar {
         let button_next_class = css_class();
         let button_today_class = css_class();
 
+        let events = self
+            .events
+            .iter()
+            .filter(|event| !event.occurrences.is_empty())
+            .collect::<Vec<_>>();
+
         let calendar_fn_input = json!({
-            "events": self.events,
+            "events": events,
             "selectors": {
                 "calendarContainer": format!(".{calendar_container_class}"),
                 "dateRange": format!(".{date_range_class}"),
@@ -28,9 +28,12 @@ pub(crate) struct HomePage {
     events: Vec<CalendarEvent>,
     base: PageBase,
     add_page_path: RelativePathBuf,
+    mobs_page_path: RelativePathBuf,
     fullcalendar_path: RelativePathBuf,
     rrule_path: RelativePathBuf,
     fullcalendar_rrule_path: RelativePathBuf,
+    calendar_feed_path: RelativePathBuf,
+    upcoming_sessions: components::UpcomingSessions,
 }
 
 impl HomePage {
@@ -41,9 +44,12 @@ impl HomePage {
         events: Vec<CalendarEvent>,
         base: PageBase,
         add_page_path: RelativePathBuf,
+        mobs_page_path: RelativePathBuf,
         fullcalendar_path: RelativePathBuf,
         rrule_path: RelativePathBuf,
         fullcalendar_rrule_path: RelativePathBuf,
+        calendar_feed_path: RelativePathBuf,
+        upcoming_sessions: components::UpcomingSessions,
     ) -> Self {
         Self {
             participants,
@@ -51,9 +57,12 @@ impl HomePage {
             events,
             base,
             add_page_path,
+            mobs_page_path,
             fullcalendar_path,
             rrule_path,
             fullcalendar_rrule_path,
+            calendar_feed_path,
+            upcoming_sessions,
         }
     }
 }
@@ -70,11 +79,20 @@ impl Render for HomePage {
 
         let content = html! {
             (calendar)
+            (self.upcoming_sessions)
             div class=(classes!("flex", "flex-wrap", format!("gap-x-{BUTTON_GAP}"))) {
                 a
                 class=(*BUTTON_CLASSES)
                     href=(self.add_page_path)
                     { "Add your mob" }
+                a
+                    class=(*BUTTON_CLASSES)
+                    href=(self.mobs_page_path)
+                    { "All mobs" }
+                a
+                    class=(*BUTTON_CLASSES)
+                    href=(self.calendar_feed_path)
+                    { "Subscribe (iCalendar)" }
             }
             div class=(classes!("flex", "flex-wrap")) {
                 @for person in &self.participants {
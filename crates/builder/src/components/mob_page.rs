@@ -12,7 +12,7 @@ use crate::{
     components,
     constants::NAME,
     mob::{self, Mob},
-    style,
+    structured_data, style,
 };
 
 use super::PageBase;
@@ -25,9 +25,18 @@ pub(crate) struct MobPage {
     fullcalendar_path: RelativePathBuf,
     rrule_path: RelativePathBuf,
     fullcalendar_rrule_path: RelativePathBuf,
+    calendar_feed_path: RelativePathBuf,
+    upcoming_sessions: components::UpcomingSessions,
+    /// Each tag with the path of its page
+    tags: Vec<(mob::Tag, RelativePathBuf)>,
+    /// Each participant with the path of their page, if public
+    participants: Vec<(Participant, Option<RelativePathBuf>)>,
+    /// JSON-LD for search engines
+    structured_data: serde_json::Value,
 }
 
 impl MobPage {
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn new(
         mob: Mob,
         links: Vec<LinkElement>,
@@ -36,6 +45,11 @@ impl MobPage {
         fullcalendar_path: RelativePathBuf,
         rrule_path: RelativePathBuf,
         fullcalendar_rrule_path: RelativePathBuf,
+        calendar_feed_path: RelativePathBuf,
+        upcoming_sessions: components::UpcomingSessions,
+        tags: Vec<(mob::Tag, RelativePathBuf)>,
+        participants: Vec<(Participant, Option<RelativePathBuf>)>,
+        structured_data: serde_json::Value,
     ) -> Self {
         Self {
             mob,
@@ -45,21 +59,19 @@ impl MobPage {
             fullcalendar_path,
             rrule_path,
             fullcalendar_rrule_path,
+            calendar_feed_path,
+            upcoming_sessions,
+            tags,
+            participants,
+            structured_data,
         }
     }
 }
 
+#[allow(clippy::too_many_lines)]
 impl Render for MobPage {
     fn render(&self) -> maud::Markup {
-        let status_content = match self.mob.status() {
-            mob::Status::Short(join_content) | mob::Status::Open(join_content) => {
-                Some(join_content.clone())
-            }
-            mob::Status::Full(join_content) => join_content.clone(),
-            mob::Status::Public(join_content) => Some(join_content.clone()),
-            mob::Status::Renamed(_id) => None,
-            mob::Status::Terminated(content) => content.clone(),
-        };
+        let status_content = self.mob.status().content();
 
         let calendar = (!matches!(self.mob.status(), mob::Status::Terminated(_))).then(|| {
             components::Calendar::new(
@@ -89,6 +101,13 @@ impl Render for MobPage {
                     @if let Some(subtitle) = &self.mob.subtitle() {
                         (subtitle)
                     }
+                    @if !self.tags.is_empty() {
+                        div class=(classes!("flex", "flex-wrap", "justify-center", "gap-x-[1ch]")) {
+                            @for (tag, path) in &self.tags {
+                                a href=(path) { "#" (tag) }
+                            }
+                        }
+                    }
                 }
 
                 @if !self.links.is_empty() {
@@ -102,10 +121,10 @@ impl Render for MobPage {
                 div class=(classes!("py-12")) {
                     h2 { "Participants" }
                     div class=(classes!("font-bold")) {
-                        @for mob_participant in self.mob.participants() {
-                            @match mob_participant {
-                                Participant::Hidden => div { "(Anonymous participant)" },
-                                Participant::Public(person) => a class=(classes!("block")) href=(person.social_url()) { (person.name()) },
+                        @for (mob_participant, person_page_path) in &self.participants {
+                            @match (mob_participant, person_page_path) {
+                                (Participant::Public(person), Some(person_page_path)) => a class=(classes!("block")) href=(person_page_path) { (person.name()) },
+                                _ => div { "(Anonymous participant)" },
                             }
                         }
                     }
@@ -129,6 +148,14 @@ impl Render for MobPage {
 
             @if let Some(calendar) = calendar {
                 (calendar)
+                (self.upcoming_sessions)
+
+                div class=(classes!("flex", "flex-wrap", format!("gap-x-{}", style::BUTTON_GAP))) {
+                    a
+                        class=(*style::BUTTON_CLASSES)
+                        href=(self.calendar_feed_path)
+                        { "Subscribe (iCalendar)" }
+                }
             }
         };
 
@@ -136,7 +163,7 @@ impl Render for MobPage {
             .clone()
             .into_page(
                 Some(self.mob.title().as_str().to_owned().into()),
-                None,
+                Some(structured_data::script(&self.structured_data)),
                 content,
                 classes!("flex", "flex-col", "gap-6"),
                 components::page_base::PageDescription::from(format!(
@@ -159,10 +186,14 @@ pub(crate) fn event_content_template(
     _mob: &Mob,
     _expected_files: &mut ExpectedFiles,
 ) -> Markup {
+    let timezone = start.timezone();
     let start = start.format("%k:%M").to_string();
     let end = end.format("%k:%M").to_string();
+
+    // the visitor's local time differs between occurrences, so it is filled in at runtime
     let content = html! {
-        (start) "–" (end) " UTC"
+        div { (start) "–" (end) " " (timezone.name()) }
+        div class=(classes!("text-sm")) { "Your time: " span data-local-time {} }
     };
     content
 }
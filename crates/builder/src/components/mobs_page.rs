@@ -0,0 +1,189 @@
+use chrono::{DateTime, Utc};
+use getset::Getters;
+use maud::{html, Markup, PreEscaped, Render};
+
+use crate::{
+    constants::NAME,
+    mob::{self, Mob, Participant},
+    relative_path::RelativePathBuf,
+};
+
+use super::PageBase;
+
+#[derive(Debug, Clone)]
+pub(crate) struct MobsPage {
+    base: PageBase,
+    status_legend: mob::status::Legend,
+    /// Each status variant with its description and mobs
+    groups: Vec<(String, mob::status::Description, Vec<MobsPageEntry>)>,
+    archive: Vec<MobsPageEntry>,
+    as_of: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Getters)]
+pub(crate) struct MobsPageEntry {
+    #[getset(get = "pub(crate)")]
+    mob: Mob,
+    path: RelativePathBuf,
+    next_session: Option<DateTime<rrule::Tz>>,
+}
+
+impl MobsPage {
+    pub(crate) fn new(
+        base: PageBase,
+        status_legend: mob::status::Legend,
+        groups: Vec<(String, mob::status::Description, Vec<MobsPageEntry>)>,
+        archive: Vec<MobsPageEntry>,
+        as_of: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            base,
+            status_legend,
+            groups,
+            archive,
+            as_of,
+        }
+    }
+}
+
+impl MobsPageEntry {
+    pub(crate) fn new(
+        mob: Mob,
+        path: RelativePathBuf,
+        next_session: Option<DateTime<rrule::Tz>>,
+    ) -> Self {
+        Self {
+            mob,
+            path,
+            next_session,
+        }
+    }
+}
+
+fn entries(entries: &[MobsPageEntry]) -> Markup {
+    html! {
+        ul data-sortable class=(classes!("flex", "flex-col", "gap-4")) {
+            @for entry in entries {
+                (entry)
+            }
+        }
+    }
+}
+
+impl Render for MobsPageEntry {
+    fn render(&self) -> Markup {
+        let mob = &self.mob;
+
+        // ISO 8601 in UTC sorts chronologically; empty for mobs without a next session
+        let next_session_sort_key = self.next_session.map_or(String::new(), |next_session| {
+            next_session.with_timezone(&Utc).to_rfc3339()
+        });
+
+        html! {
+            li
+                data-title=(mob.title().as_str().to_lowercase())
+                data-next-session=(next_session_sort_key)
+            {
+                a class=(classes!("font-bold")) href=(self.path) { (mob.title()) }
+
+                @if let Some(status_indicator) = mob.status().indicator() {
+                    " " (status_indicator)
+                }
+
+                @if let Some(subtitle) = mob.subtitle() {
+                    p { (subtitle) }
+                }
+
+                @if let Some(next_session) = self.next_session {
+                    p {
+                        "Next session: "
+                        time datetime=(next_session.to_rfc3339()) {
+                            (next_session.format("%a %F %k:%M").to_string())
+                            " "
+                            (next_session.timezone().name())
+                        }
+                    }
+                }
+
+                div class=(classes!("flex", "flex-wrap", "gap-1")) {
+                    @for participant in mob.participants() {
+                        @if let Participant::Public(person) = participant {
+                            @if let Some(avatar_url) = person.avatar_url() {
+                                a href=(person.social_url()) class=(classes!("w-8")) {
+                                    img alt=(person.name()) title=(person.name()) src=(avatar_url);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Render for MobsPage {
+    fn render(&self) -> Markup {
+        let content = html! {
+            h1 class=(classes!("text-4xl")) { "Mobs" }
+
+            (self.status_legend)
+
+            // sorting requires JavaScript, so the control is revealed by the script
+            label hidden class=(classes!("flex", "gap-[1ch]")) {
+                "Sort by"
+                select data-sort class=(classes!("bg-gray-700")) {
+                    option value="title" { "title" }
+                    option value="nextSession" { "next session" }
+                }
+            }
+
+            @for (variant, description, group_entries) in &self.groups {
+                section {
+                    h2 class=(classes!("text-2xl")) { (variant) }
+                    p class=(classes!("mb-2")) { (description) }
+                    (entries(group_entries))
+                }
+            }
+
+            @if !self.archive.is_empty() {
+                section {
+                    h2 class=(classes!("text-2xl")) { "Archive" }
+                    p class=(classes!("mb-2")) { "Mobs that have been terminated." }
+                    (entries(&self.archive))
+                }
+            }
+
+            p class=(classes!("text-sm")) { "Next sessions as of " (self.as_of.format("%F").to_string()) "." }
+
+            script {
+                (PreEscaped("
+                    const select = document.querySelector('[data-sort]')
+                    select.parentElement.hidden = false
+                    select.addEventListener('change', () => {
+                        for (const list of document.querySelectorAll('[data-sortable]')) {
+                            const items = [...list.children]
+                            // items without a key, such as mobs without a next session, are last
+                            items.sort((a, b) => {
+                                const [keyA, keyB] = [a.dataset[select.value], b.dataset[select.value]]
+                                if (!keyA || !keyB) return !keyA - !keyB
+                                return keyA.localeCompare(keyB)
+                            })
+                            list.append(...items)
+                        }
+                    })
+                "))
+            }
+        };
+
+        self.base
+            .clone()
+            .into_page(
+                Some("Mobs".to_owned().into()),
+                None,
+                content,
+                classes!("flex", "flex-col", "gap-6"),
+                format!("All the mobs on {NAME}, current and past").into(),
+            )
+            .render()
+    }
+}
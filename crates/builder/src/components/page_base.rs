@@ -1,16 +1,18 @@
 use std::fmt::Display;
 
-use chrono::Utc;
 use maud::{html, Markup, Render, DOCTYPE};
 use ssg_child::sources::ExpectedFiles;
 
 use crate::{
+    atom_feed,
     constants::{DESCRIPTION, GITHUB_ORGANIZATION_URL, NAME, REPO_URL, ZULIP_URL},
     expected_files::ExpectedFilesExt,
     fonts,
+    google_font::TrueTypeFont,
     html::Classes,
     relative_path::RelativePathBuf,
-    style,
+    social_preview, style, tailwind,
+    url::Url,
 };
 
 #[derive(Debug, Clone)]
@@ -51,21 +53,55 @@ impl From<String> for PageDescription {
 
 #[derive(Debug, Clone)]
 pub(crate) struct PageBase {
+    /// Where the website is served, for the URLs of link previews
+    base_url: Url,
     index: RelativePathBuf,
     current: RelativePathBuf,
     zulip_logo: RelativePathBuf,
     inverticat: RelativePathBuf,
     twitter_logo: RelativePathBuf,
+    feed: RelativePathBuf,
+    stylesheet: RelativePathBuf,
+    social_image: RelativePathBuf,
+    /// Each font with the path of its file
+    fonts: Vec<(TrueTypeFont, RelativePathBuf)>,
 }
 
 impl PageBase {
-    pub(crate) fn new(expected_files: &mut ExpectedFiles, current_path: RelativePathBuf) -> Self {
+    pub(crate) fn new(
+        expected_files: &mut ExpectedFiles,
+        current_path: RelativePathBuf,
+        base_url: &Url,
+    ) -> Self {
         Self {
+            base_url: base_url.clone(),
             index: expected_files.insert_("/index.html"),
             current: current_path,
             zulip_logo: expected_files.insert_("/zulip_logo.svg"),
             inverticat: expected_files.insert_("/inverticat.svg"),
             twitter_logo: expected_files.insert_("/twitter_logo.svg"),
+            feed: expected_files.insert_(atom_feed::PATH),
+            stylesheet: expected_files.insert_(tailwind::PATH),
+            social_image: expected_files.insert_(social_preview::SITE_PATH),
+            fonts: fonts::ALL
+                .iter()
+                .map(|font| {
+                    let path = expected_files.insert_(font.path());
+                    (font.clone(), path)
+                })
+                .collect(),
+        }
+    }
+
+    pub(crate) fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Instead of the site's preview image, when the page is shared
+    pub(crate) fn with_social_image(self, social_image: RelativePathBuf) -> Self {
+        Self {
+            social_image,
+            ..self
         }
     }
 
@@ -101,13 +137,15 @@ pub(crate) struct Page {
 impl Render for Page {
     fn render(&self) -> Markup {
         const NAV_ICON_SIZE: u8 = 32;
-        let version = Utc::now().timestamp_millis();
 
         let title = self
             .title
             .clone()
             .map_or(NAME.to_owned(), |title| format!("{title}; {NAME}"));
 
+        // link previews require absolute URLs
+        let absolute = |path: &RelativePathBuf| self.base.base_url.join(&path.to_string()).unwrap();
+
         let brand_classes = classes!("tracking-widest", "text-center");
 
         let root_classes = classes![
@@ -150,12 +188,21 @@ impl Render for Page {
                   meta charset="utf-8";
                   meta name="description" content=(self.description);
                   meta name="viewport" content="width=device-width, initial-scale=1.0";
+                  meta property="og:type" content="website";
+                  meta property="og:site_name" content=(NAME);
+                  meta property="og:title" content=(title);
+                  meta property="og:description" content=(self.description);
+                  meta property="og:url" content=(absolute(&self.base.current));
+                  meta property="og:image" content=(absolute(&self.base.social_image));
+                  meta name="twitter:card" content="summary_large_image";
+                  meta name="twitter:site" content="@mobusoperandi";
+                  link rel="alternate" type="application/atom+xml" title={ "Mobs; " (NAME) } href=(self.base.feed);
                   @if let Some(head_content) = &self.head_content {
                       (head_content)
                   }
-                  link rel="stylesheet" href={ "/index.css?v=" (version) };
+                  link rel="stylesheet" href=(self.base.stylesheet);
                   style {
-                    @for font in fonts::ALL.as_slice() { (font) }
+                    @for (font, path) in &self.base.fonts { (font.font_face(path)) }
                   }
                 }
                 body class=(body_classes) {
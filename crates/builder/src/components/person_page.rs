@@ -0,0 +1,88 @@
+use maud::{html, Markup, Render};
+
+use crate::{
+    constants::NAME,
+    mob::{Mob, Person},
+    relative_path::RelativePathBuf,
+};
+
+use super::PageBase;
+
+#[derive(Debug, Clone)]
+pub(crate) struct PersonPage {
+    base: PageBase,
+    person: Person,
+    /// Each mob the person participates in with the path of its page
+    mobs: Vec<(Mob, RelativePathBuf)>,
+}
+
+impl PersonPage {
+    pub(crate) fn new(base: PageBase, person: Person, mobs: Vec<(Mob, RelativePathBuf)>) -> Self {
+        Self { base, person, mobs }
+    }
+
+    fn mob_list(&self, active: bool) -> Markup {
+        html! {
+            ul class=(classes!("flex", "flex-col", "gap-2")) {
+                @for (mob, path) in self.mobs.iter().filter(|(mob, _)| mob.is_active() == active) {
+                    li {
+                        a class=(classes!("font-bold")) href=(path) { (mob.title()) }
+
+                        @if let Some(status_indicator) = mob.status().indicator() {
+                            " " (status_indicator)
+                        }
+
+                        @if let Some(subtitle) = mob.subtitle() {
+                            (subtitle)
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Render for PersonPage {
+    fn render(&self) -> Markup {
+        let has_current = self.mobs.iter().any(|(mob, _)| mob.is_active());
+        let has_past = self.mobs.iter().any(|(mob, _)| !mob.is_active());
+
+        let content = html! {
+            div class=(classes!("flex", "items-center", "gap-4")) {
+                @if let Some(avatar_url) = self.person.avatar_url() {
+                    img class=(classes!("w-20")) alt=(self.person.name()) src=(avatar_url);
+                }
+
+                div {
+                    h1 class=(classes!("text-4xl")) { (self.person.name()) }
+                    a href=(self.person.social_url()) { (self.person.social_url()) }
+                }
+            }
+
+            @if has_current {
+                section {
+                    h2 class=(classes!("text-2xl")) { "Mobs" }
+                    (self.mob_list(true))
+                }
+            }
+
+            @if has_past {
+                section {
+                    h2 class=(classes!("text-2xl")) { "Past mobs" }
+                    (self.mob_list(false))
+                }
+            }
+        };
+
+        self.base
+            .clone()
+            .into_page(
+                Some(self.person.name().to_string().into()),
+                None,
+                content,
+                classes!("flex", "flex-col", "gap-6"),
+                format!("{} on {NAME}", self.person.name()).into(),
+            )
+            .render()
+    }
+}
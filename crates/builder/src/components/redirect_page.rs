@@ -20,6 +20,7 @@ impl Render for RedirectPage {
 
         let head_content = Some(html! {
             meta http-equiv="refresh" content=(format!("5; url={}", self.target));
+            link rel="canonical" href=(self.base.base_url().join(&self.target).unwrap());
         });
 
         let content = html! {
@@ -0,0 +1,71 @@
+use maud::{html, Render};
+
+use crate::{
+    constants::NAME,
+    mob::{Mob, Tag},
+    relative_path::RelativePathBuf,
+};
+
+use super::PageBase;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TagPage {
+    base: PageBase,
+    tag: Tag,
+    /// Each mob with the path of its page
+    mobs: Vec<(Mob, RelativePathBuf)>,
+    tags_page_path: RelativePathBuf,
+}
+
+impl TagPage {
+    pub(crate) fn new(
+        base: PageBase,
+        tag: Tag,
+        mobs: Vec<(Mob, RelativePathBuf)>,
+        tags_page_path: RelativePathBuf,
+    ) -> Self {
+        Self {
+            base,
+            tag,
+            mobs,
+            tags_page_path,
+        }
+    }
+}
+
+impl Render for TagPage {
+    fn render(&self) -> maud::Markup {
+        let content = html! {
+            h1 class=(classes!("text-4xl")) { "#" (self.tag) }
+
+            ul class=(classes!("flex", "flex-col", "gap-2")) {
+                @for (mob, path) in &self.mobs {
+                    li {
+                        a class=(classes!("font-bold")) href=(path) { (mob.title()) }
+
+                        @if let Some(status_indicator) = mob.status().indicator() {
+                            " " (status_indicator)
+                        }
+
+                        @if let Some(subtitle) = mob.subtitle() {
+                            (subtitle)
+                        }
+                    }
+                }
+            }
+
+            a href=(self.tags_page_path) { "All tags" }
+        };
+
+        self.base
+            .clone()
+            .into_page(
+                Some(format!("#{}", self.tag).into()),
+                None,
+                content,
+                classes!("flex", "flex-col", "gap-6"),
+                format!("Mobs about {} on {NAME}", self.tag).into(),
+            )
+            .render()
+    }
+}
@@ -0,0 +1,46 @@
+use maud::{html, Render};
+
+use crate::{constants::NAME, mob::Tag, relative_path::RelativePathBuf};
+
+use super::PageBase;
+
+#[derive(Debug, Clone)]
+pub(crate) struct TagsPage {
+    base: PageBase,
+    /// Each tag with the number of mobs and the path of its page
+    tags: Vec<(Tag, usize, RelativePathBuf)>,
+}
+
+impl TagsPage {
+    pub(crate) fn new(base: PageBase, tags: Vec<(Tag, usize, RelativePathBuf)>) -> Self {
+        Self { base, tags }
+    }
+}
+
+impl Render for TagsPage {
+    fn render(&self) -> maud::Markup {
+        let content = html! {
+            h1 class=(classes!("text-4xl")) { "Tags" }
+
+            ul class=(classes!("flex", "flex-wrap", "gap-x-[2ch]")) {
+                @for (tag, count, path) in &self.tags {
+                    li {
+                        a href=(path) { "#" (tag) }
+                        " (" (count) ")"
+                    }
+                }
+            }
+        };
+
+        self.base
+            .clone()
+            .into_page(
+                Some("Tags".to_owned().into()),
+                None,
+                content,
+                classes!("flex", "flex-col", "gap-6"),
+                format!("Topics of the mobs on {NAME}").into(),
+            )
+            .render()
+    }
+}
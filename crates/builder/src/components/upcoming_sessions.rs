@@ -0,0 +1,83 @@
+use chrono::{DateTime, Duration, Utc};
+use maud::{html, Markup, Render};
+
+use crate::mob::title::Title;
+use crate::relative_path::RelativePathBuf;
+use crate::style;
+
+const WEEKS: i64 = 4;
+
+/// A list of the sessions in the weeks following the build, for visitors without JavaScript
+/// and for search engines, which the calendar is not rendered for
+#[derive(Debug, Clone)]
+pub(crate) struct UpcomingSessions {
+    as_of: DateTime<Utc>,
+    sessions: Vec<UpcomingSession>,
+}
+
+/// The mob's title and page path are included for listings of multiple mobs
+#[derive(Debug, Clone)]
+pub(crate) struct UpcomingSession {
+    start: DateTime<rrule::Tz>,
+    end: DateTime<rrule::Tz>,
+    mob: Option<(Title, RelativePathBuf)>,
+}
+
+impl UpcomingSessions {
+    pub(crate) fn new(as_of: DateTime<Utc>, mut sessions: Vec<UpcomingSession>) -> Self {
+        sessions.sort_by_key(|session| session.start);
+        Self { as_of, sessions }
+    }
+
+    pub(crate) fn until(as_of: DateTime<Utc>) -> DateTime<Utc> {
+        as_of + Duration::weeks(WEEKS)
+    }
+}
+
+impl UpcomingSession {
+    pub(crate) fn new(
+        start: DateTime<rrule::Tz>,
+        end: DateTime<rrule::Tz>,
+        mob: Option<(Title, RelativePathBuf)>,
+    ) -> Self {
+        Self { start, end, mob }
+    }
+}
+
+impl Render for UpcomingSessions {
+    fn render(&self) -> Markup {
+        html! {
+            noscript {
+                section class=(*style::PROSE_CLASSES) {
+                    h2 { "Upcoming sessions" }
+                    p { "For the " (WEEKS) " weeks as of " (self.as_of.format("%F").to_string()) "." }
+
+                    @if self.sessions.is_empty() {
+                        p { "None." }
+                    } @else {
+                        ul {
+                            @for session in &self.sessions {
+                                li { (session) }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Render for UpcomingSession {
+    fn render(&self) -> Markup {
+        html! {
+            time datetime=(self.start.to_rfc3339()) {
+                (self.start.format("%a %F %k:%M").to_string())
+            }
+            "–" (self.end.format("%k:%M").to_string()) " " (self.start.timezone().name())
+
+            @if let Some((title, path)) = &self.mob {
+                " " a href=(path) { (title) }
+            }
+        }
+    }
+}
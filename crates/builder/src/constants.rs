@@ -4,6 +4,7 @@ use crate::url::Url;
 
 pub(crate) const NAME: &str = "Mobus Operandi";
 pub(crate) const DESCRIPTION: &str = "A mob programming community";
+pub(crate) const DOMAIN: &str = "mobusoperandi.com";
 
 pub(crate) const MOBS_DIR: &str = "mobs";
 
@@ -0,0 +1,95 @@
+use csscolorparser::Color;
+
+// https://www.w3.org/TR/WCAG21/#contrast-minimum
+pub(crate) const AA_MINIMUM_RATIO: f64 = 4.5;
+
+// https://www.w3.org/TR/WCAG21/#dfn-contrast-ratio
+pub(crate) fn ratio(a: &Color, b: &Color) -> f64 {
+    let (lighter, darker) = {
+        let a = relative_luminance(a);
+        let b = relative_luminance(b);
+
+        if a > b {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+/// The color closest in lightness to `text` that has AA contrast with `background`
+///
+/// Hue and saturation are kept.
+pub(crate) fn nearest_passing_text_color(text: &Color, background: &Color) -> Color {
+    let (hue, saturation, lightness, alpha) = text.to_hsla();
+    let with_lightness = |lightness| Color::from_hsla(hue, saturation, lightness, alpha);
+    let passes = |lightness| ratio(&with_lightness(lightness), background) >= AA_MINIMUM_RATIO;
+
+    // luminance is monotonic in lightness, so each direction is a binary search
+    // between the current lightness, which fails, and an extreme
+    let nearest_passing_lightness = [0.0, 1.0]
+        .into_iter()
+        .filter(|&extreme| passes(extreme))
+        .map(|extreme| {
+            let (mut failing, mut passing) = (lightness, extreme);
+
+            for _ in 0..32 {
+                let middle = failing + (passing - failing) / 2.0;
+
+                if passes(middle) {
+                    passing = middle;
+                } else {
+                    failing = middle;
+                }
+            }
+
+            passing
+        })
+        .min_by(|a: &f64, b: &f64| (a - lightness).abs().total_cmp(&(b - lightness).abs()))
+        // with any background, either black or white passes
+        .expect("no passing lightness");
+
+    with_lightness(nearest_passing_lightness)
+}
+
+// https://www.w3.org/TR/WCAG21/#dfn-relative-luminance
+fn relative_luminance(color: &Color) -> f64 {
+    let linear = |channel: f64| {
+        if channel <= 0.039_28 {
+            channel / 12.92
+        } else {
+            ((channel + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * linear(color.r) + 0.7152 * linear(color.g) + 0.0722 * linear(color.b)
+}
+
+#[cfg(test)]
+mod test {
+    use csscolorparser::Color;
+
+    use super::{nearest_passing_text_color, ratio, AA_MINIMUM_RATIO};
+
+    #[test]
+    fn black_on_white() {
+        let black = Color::new(0.0, 0.0, 0.0, 1.0);
+        let white = Color::new(1.0, 1.0, 1.0, 1.0);
+
+        assert!((ratio(&black, &white) - 21.0).abs() < 1e-9);
+        assert!((ratio(&white, &black) - 21.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn nearest_passing_text_color_passes() {
+        let background: Color = "Peru".parse().unwrap();
+        let text: Color = "SeaShell".parse().unwrap();
+        assert!(ratio(&text, &background) < AA_MINIMUM_RATIO);
+
+        let suggested = nearest_passing_text_color(&text, &background);
+
+        assert!(ratio(&suggested, &background) >= AA_MINIMUM_RATIO);
+    }
+}
@@ -0,0 +1,155 @@
+use std::fmt::{self, Display};
+
+use camino::{Utf8Path, Utf8PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, derive_more::Display, clap::ValueEnum)]
+pub(crate) enum Severity {
+    #[display(fmt = "error")]
+    Error,
+    #[display(fmt = "warning")]
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Diagnostic {
+    severity: Severity,
+    file: Utf8PathBuf,
+    location: Option<Location>,
+    field: Option<String>,
+    message: String,
+}
+
+impl Diagnostic {
+    pub(crate) fn new(
+        severity: Severity,
+        file: &Utf8Path,
+        location: Option<Location>,
+        field: Option<String>,
+        message: String,
+    ) -> Self {
+        Self {
+            severity,
+            file: file.to_owned(),
+            location,
+            field,
+            message,
+        }
+    }
+
+    pub(crate) fn error(file: &Utf8Path, field: Option<String>, message: String) -> Self {
+        Self::new(Severity::Error, file, None, field, message)
+    }
+}
+
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.file)?;
+
+        if let Some(location) = &self.location {
+            write!(f, ":{location}")?;
+        }
+
+        write!(f, ": {}: ", self.severity)?;
+
+        if let Some(field) = &self.field {
+            write!(f, "{field}: ")?;
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Location {
+    line: usize,
+    column: usize,
+}
+
+impl From<serde_yaml::Location> for Location {
+    fn from(location: serde_yaml::Location) -> Self {
+        Self {
+            line: location.line(),
+            column: location.column(),
+        }
+    }
+}
+
+impl Display for Location {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Report(Vec<Diagnostic>);
+
+impl Report {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub(crate) fn has_errors(&self) -> bool {
+        self.count(Severity::Error) > 0
+    }
+
+    fn count(&self, severity: Severity) -> usize {
+        self.0
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    }
+}
+
+impl From<Diagnostic> for Report {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Self(vec![diagnostic])
+    }
+}
+
+impl FromIterator<Diagnostic> for Report {
+    fn from_iter<T: IntoIterator<Item = Diagnostic>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl Extend<Diagnostic> for Report {
+    fn extend<T: IntoIterator<Item = Diagnostic>>(&mut self, iter: T) {
+        self.0.extend(iter);
+    }
+}
+
+impl IntoIterator for Report {
+    type Item = Diagnostic;
+    type IntoIter = std::vec::IntoIter<Diagnostic>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for diagnostic in &self.0 {
+            writeln!(f, "{diagnostic}")?;
+        }
+
+        let errors = self.count(Severity::Error);
+        let warnings = self.count(Severity::Warning);
+
+        write!(f, "{errors} error{}", plural(errors))?;
+
+        if warnings > 0 {
+            write!(f, ", {warnings} warning{}", plural(warnings))?;
+        }
+
+        Ok(())
+    }
+}
+
+fn plural(count: usize) -> &'static str {
+    if count == 1 {
+        ""
+    } else {
+        "s"
+    }
+}
@@ -1,29 +1,86 @@
-use camino::Utf8Path;
-use itertools::Itertools;
-use ssg_child::FileSpec;
+use std::collections::BTreeSet;
 
-use crate::{fonts, graphic_file_specs, pages};
+use ssg_child::{FileSpec, Fingerprints};
 
-pub(crate) fn get(mobs_path: &Utf8Path) -> impl Iterator<Item = FileSpec> {
-    let fonts = fonts::all();
-    let mobs = crate::mob::get_all(mobs_path).into_iter().collect_vec();
-    let pages = pages::all(mobs);
+use crate::{
+    api, atom_feed, calendar_feeds, fonts, graphic_file_specs, json_schema,
+    mob::{Mob, Status},
+    pages, redirects, sitemap, social_preview, tailwind,
+    url::Url,
+};
 
-    let calendar_library = FileSpec::new(
+pub(crate) async fn get(mobs: Vec<Mob>, base_url: &Url) -> impl Iterator<Item = FileSpec> {
+    let mut fingerprints = Fingerprints::default();
+
+    let fonts = fonts::all(&mut fingerprints);
+    let graphics = graphic_file_specs::get(&mut fingerprints);
+
+    let calendar_library = FileSpec::fingerprinted(
         "/fullcalendar.js",
-        include_bytes!(env!("FULLCALENDAR")).as_slice(),
-    );
+        include_bytes!(env!("FULLCALENDAR")),
+        &mut fingerprints,
+    )
+    .with_label("script");
 
-    let rrule_library = FileSpec::new("/rrule.js", include_bytes!(env!("RRULE")).as_slice());
+    let rrule_library = FileSpec::fingerprinted(
+        "/rrule.js",
+        include_bytes!(env!("RRULE")),
+        &mut fingerprints,
+    )
+    .with_label("script");
 
-    let fullcalendar_rrule = FileSpec::new(
+    let fullcalendar_rrule = FileSpec::fingerprinted(
         "/fullcalendar_rrule.js",
-        include_bytes!(env!("FULLCALENDAR_RRULE")).as_slice(),
+        include_bytes!(env!("FULLCALENDAR_RRULE")),
+        &mut fingerprints,
+    )
+    .with_label("script");
+
+    let calendar_feeds = calendar_feeds::all(&mobs);
+    let api = api::all(&mobs);
+    let atom_feed = atom_feed::file_spec(&mobs, base_url);
+    let social_previews = social_preview::all(&mobs);
+    let redirects = redirects::file_specs(&mobs);
+
+    // the pages of renamed mobs only redirect
+    let redirect_paths = mobs
+        .iter()
+        .filter(|mob| matches!(mob.status(), Status::Renamed(_)))
+        .map(|mob| format!("/mobs/{}.html", mob.id()))
+        .collect::<BTreeSet<_>>();
+
+    // The pages are rendered twice, because the stylesheet and the pages depend on each other:
+    // the stylesheet has the rules for the classes that the pages use,
+    // and the pages refer to it by its fingerprinted path, which is derived from its contents.
+    // The first render refers to the plain path, and is only scanned for classes,
+    // which do not depend on the stylesheet's path
+    let unstyled_pages = pages::all(mobs.clone(), base_url, &fingerprints).collect::<Vec<_>>();
+    let stylesheet = tailwind::file_spec(&unstyled_pages, &mut fingerprints).await;
+    let pages = pages::all(mobs, base_url, &fingerprints);
+
+    let file_specs = [
+        calendar_library,
+        rrule_library,
+        fullcalendar_rrule,
+        json_schema::file_spec(),
+        atom_feed,
+        stylesheet,
+    ]
+    .into_iter()
+    .chain(fonts)
+    .chain(graphics)
+    .chain(calendar_feeds)
+    .chain(api)
+    .chain(social_previews)
+    .chain(redirects)
+    .chain(pages.map(FileSpec::from))
+    .collect::<Vec<_>>();
+
+    let sitemap = sitemap::file_specs(
+        base_url,
+        file_specs.iter().map(|file_spec| file_spec.path().as_str()),
+        &redirect_paths,
     );
 
-    [calendar_library, rrule_library, fullcalendar_rrule]
-        .into_iter()
-        .chain(fonts)
-        .chain(graphic_file_specs::get())
-        .chain(pages)
+    file_specs.into_iter().chain(sitemap)
 }
@@ -1,5 +1,5 @@
 use once_cell::sync::Lazy;
-use ssg_child::FileSpec;
+use ssg_child::{FileSpec, Fingerprints};
 
 use crate::google_font::TrueTypeFont;
 
@@ -8,7 +8,8 @@ pub(crate) const VOLLKORN: TrueTypeFont =
 
 pub(crate) static ALL: Lazy<[TrueTypeFont; 1]> = Lazy::new(|| [VOLLKORN.clone()]);
 
-pub(crate) fn all() -> [FileSpec; 1] {
-    ALL.clone()
-        .map(|font| FileSpec::new(format!("/{}.ttf", font.family().to_lowercase()), font))
+pub(crate) fn all(fingerprints: &mut Fingerprints) -> [FileSpec; 1] {
+    ALL.clone().map(|font| {
+        FileSpec::fingerprinted(font.path(), font.bytes(), fingerprints).with_label("font")
+    })
 }
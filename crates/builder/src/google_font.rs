@@ -1,6 +1,4 @@
-use futures::future::BoxFuture;
-use maud::{Markup, PreEscaped, Render};
-use ssg_child::sources::FileSource;
+use maud::{Markup, PreEscaped};
 
 use crate::relative_path::RelativePathBuf;
 
@@ -15,34 +13,27 @@ impl TrueTypeFont {
         Self { bytes, family }
     }
 
-    pub(crate) fn family(&self) -> &str {
-        self.family
+    pub(crate) fn bytes(&self) -> &'static [u8] {
+        self.bytes
     }
 
-    pub(crate) fn filename(&self) -> RelativePathBuf {
-        format!("{}.ttf", self.family().to_lowercase()).into()
+    pub(crate) fn family(&self) -> &str {
+        self.family
     }
-}
 
-impl FileSource for TrueTypeFont {
-    fn obtain_content(
-        &self,
-    ) -> BoxFuture<Result<ssg_child::sources::FileContents, Box<dyn std::error::Error + Send>>>
-    {
-        self.bytes.obtain_content()
+    pub(crate) fn path(&self) -> String {
+        format!("/{}.ttf", self.family().to_lowercase())
     }
-}
 
-impl Render for TrueTypeFont {
-    fn render(&self) -> Markup {
+    /// With the path that the font file is expected at
+    pub(crate) fn font_face(&self, path: &RelativePathBuf) -> Markup {
         PreEscaped(format!(
             "
             @font-face {{
                 font-family: '{}';
-                src: url('/{}') format('truetype');
+                src: url('{path}') format('truetype');
             }}",
             self.family(),
-            self.filename()
         ))
     }
 }
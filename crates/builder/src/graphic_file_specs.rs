@@ -1,12 +1,54 @@
-use ssg_child::FileSpec;
+use ssg_child::{FileSpec, Fingerprints};
 
-pub(crate) fn get() -> [FileSpec; 5] {
+/// The images other than the favicon are fingerprinted, because pages refer to them
+pub(crate) fn get(fingerprints: &mut Fingerprints) -> [FileSpec; 10] {
     const FAVICON: [u8; 0] = [];
     let favicon = FileSpec::new("/favicon.ico", FAVICON.as_slice());
-    let twitter_logo = FileSpec::new("/twitter_logo.svg", include_bytes!(env!("TWITTER_LOGO")));
-    let zulip_logo = FileSpec::new("/zulip_logo.svg", include_bytes!(env!("ZULIP_LOGO")));
-    let inverticat_logo = FileSpec::new("/inverticat.svg", include_bytes!(env!("INVERTICAT_LOGO")));
-    let youtube_logo = FileSpec::new("/youtube_logo.svg", include_bytes!(env!("YOUTUBE_LOGO")));
+    let twitter_logo = FileSpec::fingerprinted(
+        "/twitter_logo.svg",
+        include_bytes!(env!("TWITTER_LOGO")),
+        fingerprints,
+    );
+    let zulip_logo = FileSpec::fingerprinted(
+        "/zulip_logo.svg",
+        include_bytes!(env!("ZULIP_LOGO")),
+        fingerprints,
+    );
+    let inverticat_logo = FileSpec::fingerprinted(
+        "/inverticat.svg",
+        include_bytes!(env!("INVERTICAT_LOGO")),
+        fingerprints,
+    );
+    let youtube_logo = FileSpec::fingerprinted(
+        "/youtube_logo.svg",
+        include_bytes!(env!("YOUTUBE_LOGO")),
+        fingerprints,
+    );
+    let twitch_logo = FileSpec::fingerprinted(
+        "/twitch_logo.svg",
+        include_bytes!(env!("TWITCH_LOGO")),
+        fingerprints,
+    );
+    let discord_logo = FileSpec::fingerprinted(
+        "/discord_logo.svg",
+        include_bytes!(env!("DISCORD_LOGO")),
+        fingerprints,
+    );
+    let mastodon_logo = FileSpec::fingerprinted(
+        "/mastodon_logo.svg",
+        include_bytes!(env!("MASTODON_LOGO")),
+        fingerprints,
+    );
+    let rss_icon = FileSpec::fingerprinted(
+        "/rss_icon.svg",
+        include_bytes!(env!("RSS_ICON")),
+        fingerprints,
+    );
+    let globe_icon = FileSpec::fingerprinted(
+        "/globe_icon.svg",
+        include_bytes!(env!("GLOBE_ICON")),
+        fingerprints,
+    );
 
     [
         favicon,
@@ -14,5 +56,11 @@ pub(crate) fn get() -> [FileSpec; 5] {
         zulip_logo,
         inverticat_logo,
         youtube_logo,
+        twitch_logo,
+        discord_logo,
+        mastodon_logo,
+        rss_icon,
+        globe_icon,
     ]
+    .map(|file_spec| file_spec.with_label("image"))
 }
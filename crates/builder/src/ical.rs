@@ -0,0 +1,252 @@
+use std::fmt::{self, Display, Write};
+
+use chrono::{DateTime, Duration, Utc};
+
+const PRODUCT_ID: &str = "-//Mobus Operandi//website//EN";
+const MAX_LINE_OCTETS: usize = 75;
+
+#[derive(Debug, Clone)]
+pub(crate) struct Calendar {
+    name: String,
+    events: Vec<Event>,
+}
+
+impl Calendar {
+    pub(crate) fn new(name: String, events: Vec<Event>) -> Self {
+        Self { name, events }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct Event {
+    uid: String,
+    timestamp: DateTime<Utc>,
+    start: DateTime<rrule::Tz>,
+    recurrence: Recurrence,
+    duration: Duration,
+    summary: String,
+    description: String,
+}
+
+impl Event {
+    pub(crate) fn new(
+        uid: String,
+        timestamp: DateTime<Utc>,
+        start: DateTime<rrule::Tz>,
+        recurrence: Recurrence,
+        duration: Duration,
+        summary: String,
+        description: String,
+    ) -> Self {
+        Self {
+            uid,
+            timestamp,
+            start,
+            recurrence,
+            duration,
+            summary,
+            description,
+        }
+    }
+}
+
+/// All date-times are in the timezone of the event's start
+#[derive(Debug, Clone)]
+pub(crate) struct Recurrence {
+    rule: Option<String>,
+    additional_starts: Vec<DateTime<rrule::Tz>>,
+    cancelled_starts: Vec<DateTime<rrule::Tz>>,
+}
+
+impl Recurrence {
+    pub(crate) fn new(
+        rule: Option<String>,
+        additional_starts: Vec<DateTime<rrule::Tz>>,
+        cancelled_starts: Vec<DateTime<rrule::Tz>>,
+    ) -> Self {
+        Self {
+            rule,
+            additional_starts,
+            cancelled_starts,
+        }
+    }
+}
+
+impl Display for Calendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_line(f, "BEGIN:VCALENDAR")?;
+        write_line(f, "VERSION:2.0")?;
+        write_line(f, &format!("PRODID:{PRODUCT_ID}"))?;
+        write_line(f, "CALSCALE:GREGORIAN")?;
+        write_line(f, &format!("X-WR-CALNAME:{}", escape_text(&self.name)))?;
+
+        for event in &self.events {
+            write!(f, "{event}")?;
+        }
+
+        write_line(f, "END:VCALENDAR")
+    }
+}
+
+impl Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_line(f, "BEGIN:VEVENT")?;
+        write_line(f, &format!("UID:{}", self.uid))?;
+        write_line(
+            f,
+            &format!("DTSTAMP:{}", self.timestamp.format("%Y%m%dT%H%M%SZ")),
+        )?;
+        let timezone = self.start.timezone();
+        let recurrence = &self.recurrence;
+
+        write_line(f, &date_times_property("DTSTART", timezone, &[self.start]))?;
+
+        if let Some(rule) = &recurrence.rule {
+            write_line(f, &format!("RRULE:{rule}"))?;
+        }
+
+        if !recurrence.additional_starts.is_empty() {
+            write_line(
+                f,
+                &date_times_property("RDATE", timezone, &recurrence.additional_starts),
+            )?;
+        }
+
+        if !recurrence.cancelled_starts.is_empty() {
+            write_line(
+                f,
+                &date_times_property("EXDATE", timezone, &recurrence.cancelled_starts),
+            )?;
+        }
+
+        write_line(f, &format!("DURATION:{}", format_duration(self.duration)))?;
+        write_line(f, &format!("SUMMARY:{}", escape_text(&self.summary)))?;
+        write_line(
+            f,
+            &format!("DESCRIPTION:{}", escape_text(&self.description)),
+        )?;
+        write_line(f, "END:VEVENT")
+    }
+}
+
+// TZID values are IANA names, which calendar clients resolve without a VTIMEZONE
+fn date_times_property(
+    name: &str,
+    timezone: rrule::Tz,
+    date_times: &[DateTime<rrule::Tz>],
+) -> String {
+    let values = date_times
+        .iter()
+        .map(|date_time| date_time.format("%Y%m%dT%H%M%S").to_string())
+        .collect::<Vec<_>>()
+        .join(",");
+
+    format!("{name};TZID={}:{values}", timezone.name())
+}
+
+fn format_duration(duration: Duration) -> String {
+    let hours = duration.num_hours();
+    let minutes = duration.num_minutes() - hours * 60;
+
+    match (hours, minutes) {
+        (0, minutes) => format!("PT{minutes}M"),
+        (hours, 0) => format!("PT{hours}H"),
+        (hours, minutes) => format!("PT{hours}H{minutes}M"),
+    }
+}
+
+fn escape_text(text: &str) -> String {
+    text.chars().fold(String::new(), |mut escaped, char| {
+        match char {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(char);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            char => escaped.push(char),
+        }
+
+        escaped
+    })
+}
+
+// content lines are folded at 75 octets, without splitting a UTF-8 sequence
+fn write_line(f: &mut fmt::Formatter<'_>, line: &str) -> fmt::Result {
+    let mut octets = 0;
+
+    for char in line.chars() {
+        if octets + char.len_utf8() > MAX_LINE_OCTETS {
+            f.write_str("\r\n ")?;
+            octets = 1;
+        }
+
+        f.write_char(char)?;
+        octets += char.len_utf8();
+    }
+
+    f.write_str("\r\n")
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, NaiveDate, TimeZone, Utc};
+
+    use super::{Calendar, Event, Recurrence};
+
+    #[test]
+    fn calendar_display() {
+        let timezone: rrule::Tz = chrono_tz::Europe::Vienna.into();
+
+        let local = |day| {
+            timezone
+                .from_local_datetime(
+                    &NaiveDate::from_ymd_opt(2023, 1, day)
+                        .unwrap()
+                        .and_hms_opt(17, 0, 0)
+                        .unwrap(),
+                )
+                .unwrap()
+        };
+
+        let event = Event::new(
+            "0.bass@mobusoperandi.com".to_owned(),
+            Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap(),
+            local(5),
+            Recurrence::new(
+                Some("FREQ=WEEKLY;BYDAY=TH".to_owned()),
+                vec![local(14)],
+                vec![local(12), local(19)],
+            ),
+            Duration::minutes(90),
+            "Bass".to_owned(),
+            "## What we do\n\nWe play; loudly, mostly.".to_owned(),
+        );
+
+        let actual = Calendar::new("Bass; Mobus Operandi".to_owned(), vec![event]).to_string();
+
+        let expected = [
+            "BEGIN:VCALENDAR",
+            "VERSION:2.0",
+            "PRODID:-//Mobus Operandi//website//EN",
+            "CALSCALE:GREGORIAN",
+            "X-WR-CALNAME:Bass\\; Mobus Operandi",
+            "BEGIN:VEVENT",
+            "UID:0.bass@mobusoperandi.com",
+            "DTSTAMP:20230101T000000Z",
+            "DTSTART;TZID=Europe/Vienna:20230105T170000",
+            "RRULE:FREQ=WEEKLY;BYDAY=TH",
+            "RDATE;TZID=Europe/Vienna:20230114T170000",
+            "EXDATE;TZID=Europe/Vienna:20230112T170000,20230119T170000",
+            "DURATION:PT1H30M",
+            "SUMMARY:Bass",
+            "DESCRIPTION:## What we do\\n\\nWe play\\; loudly\\, mostly.",
+            "END:VEVENT",
+            "END:VCALENDAR",
+            "",
+        ]
+        .join("\r\n");
+
+        assert_eq!(actual, expected);
+    }
+}
@@ -0,0 +1,250 @@
+use anyhow::{anyhow, bail, Result};
+use itertools::Itertools;
+use schema::syn;
+use serde_json::{json, Map, Value};
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{
+    components::schema::type_::ident::TypeIdent, constants::DOMAIN,
+    pages::add::INTERNAL_TYPES_DERIVE_INPUTS, syn_helpers::Attribute,
+};
+
+pub(crate) const PATH: &str = "/mob.schema.json";
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub(crate) fn url() -> String {
+    format!("https://{DOMAIN}{PATH}")
+}
+
+pub(crate) fn file_spec() -> FileSpec {
+    let schema = schema().unwrap();
+    let bytes = serde_json::to_vec_pretty(&schema).unwrap();
+
+    FileSpec::new(PATH, BytesSource::new(bytes, None)).with_label("schema")
+}
+
+fn schema() -> Result<Value> {
+    let definitions = INTERNAL_TYPES_DERIVE_INPUTS
+        .iter()
+        .map(|(ident, derive_input)| Ok((ident.as_str().to_owned(), type_schema(derive_input)?)))
+        .collect::<Result<Map<String, Value>>>()?;
+
+    let (root_ident, _) = INTERNAL_TYPES_DERIVE_INPUTS
+        .first()
+        .ok_or_else(|| anyhow!("no internal types"))?;
+
+    Ok(json!({
+        "$schema": DIALECT,
+        "$id": url(),
+        "$ref": reference(root_ident),
+        "$defs": definitions,
+    }))
+}
+
+/// Tags of enum variants, in the format of yaml-language-server's `yaml.customTags` setting
+pub(crate) fn yaml_custom_tags() -> Vec<String> {
+    INTERNAL_TYPES_DERIVE_INPUTS
+        .values()
+        .filter_map(|derive_input| {
+            let syn::Data::Enum(enum_data) = &derive_input.data else {
+                return None;
+            };
+
+            Some(enum_data.variants.clone())
+        })
+        .flatten()
+        .map(|variant| {
+            let kind = match variant.fields.iter().next().map(field_type_ident) {
+                Some(Ok(ident)) if is_mapping(&ident) => "mapping",
+                _ => "scalar",
+            };
+
+            format!("!{} {kind}", variant.ident)
+        })
+        .unique()
+        .collect()
+}
+
+fn is_mapping(ident: &TypeIdent) -> bool {
+    INTERNAL_TYPES_DERIVE_INPUTS
+        .get(ident)
+        .is_some_and(|derive_input| matches!(derive_input.data, syn::Data::Struct(_)))
+}
+
+fn type_schema(derive_input: &syn::DeriveInput) -> Result<Value> {
+    let mut schema = match &derive_input.data {
+        syn::Data::Struct(struct_data) => struct_schema(struct_data)?,
+        syn::Data::Enum(enum_data) => enum_schema(enum_data)?,
+        syn::Data::Union(_) => bail!("only struct or enum"),
+    };
+
+    describe(&mut schema, &derive_input.attrs);
+
+    Ok(schema)
+}
+
+fn struct_schema(struct_data: &syn::DataStruct) -> Result<Value> {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    for field in &struct_data.fields {
+        let ident = field
+            .ident
+            .as_ref()
+            .ok_or_else(|| anyhow!("expected an ident"))?
+            .to_string();
+
+        let (mut schema, is_required) = field_schema(&field.ty)?;
+        describe(&mut schema, &field.attrs);
+
+        if is_required {
+            required.push(ident.clone());
+        }
+
+        properties.insert(ident, schema);
+    }
+
+    Ok(json!({
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false,
+    }))
+}
+
+// YAML tags are not visible to JSON Schema validation,
+// so a variant is expressed by the schema of its value alone
+fn enum_schema(enum_data: &syn::DataEnum) -> Result<Value> {
+    let variants = enum_data
+        .variants
+        .iter()
+        .map(|variant| {
+            let mut schema = match variant.fields.iter().next() {
+                Some(field) => match field_schema(&field.ty)? {
+                    (schema, true) => schema,
+                    (schema, false) => json!({ "anyOf": [schema, { "type": "null" }] }),
+                },
+                None => json!({ "type": "null" }),
+            };
+
+            let tag = format!("!{}", variant.ident);
+            schema["title"] = tag.clone().into();
+            schema["x-yaml-tag"] = tag.into();
+            describe(&mut schema, &variant.attrs);
+
+            Ok(schema)
+        })
+        .collect::<Result<Vec<Value>>>()?;
+
+    Ok(json!({ "anyOf": variants }))
+}
+
+/// Returns the schema and whether the field is required
+fn field_schema(ty: &syn::Type) -> Result<(Value, bool)> {
+    let last_path_segment = last_path_segment(ty)?;
+
+    if last_path_segment.ident == "Option" {
+        let schema = node_schema(&type_argument(&last_path_segment.arguments)?)?;
+        Ok((schema, false))
+    } else {
+        Ok((node_schema(ty)?, true))
+    }
+}
+
+fn node_schema(ty: &syn::Type) -> Result<Value> {
+    let last_path_segment = last_path_segment(ty)?;
+    let ident: TypeIdent = last_path_segment.ident.to_string().into();
+
+    if ident == "Vec" {
+        let items = node_schema(&type_argument(&last_path_segment.arguments)?)?;
+        return Ok(json!({ "type": "array", "items": items }));
+    }
+
+    if INTERNAL_TYPES_DERIVE_INPUTS.contains_key(&ident) {
+        return Ok(json!({ "$ref": reference(&ident) }));
+    }
+
+    let schema = match ident.as_str() {
+        "String"
+        | "Title"
+        | "Subtitle"
+        | "PersonName"
+        | "PersonKey"
+        | "Markdown"
+        | "Id"
+        | "RecurrenceFrequency"
+        | "Color" => json!({ "type": "string" }),
+        "Url" => json!({ "type": "string", "format": "uri" }),
+        "NaiveDate" => json!({ "type": "string", "format": "date" }),
+        "Time" => json!({
+            "type": "string",
+            "pattern": "^([01][0-9]|2[0-3]):[0-5][0-9]$",
+        }),
+        "Tag" => json!({ "type": "string", "pattern": "^[a-z0-9-]+$" }),
+        "Handle" => json!({ "type": "string", "pattern": "^[^\\s?#\\\\]+$" }),
+        "MastodonAccount" => json!({ "type": "string", "pattern": "^@[^@/\\s]+@[^@/\\s]+$" }),
+        "Minutes" => json!({ "type": "integer", "minimum": 0, "maximum": u16::MAX }),
+        "Tz" => json!({
+            "type": "string",
+            "enum": chrono_tz::TZ_VARIANTS.iter().map(|tz| tz.name()).collect::<Vec<_>>(),
+        }),
+        _ => bail!("no JSON schema for type {}", ident.as_str()),
+    };
+
+    Ok(schema)
+}
+
+fn last_path_segment(ty: &syn::Type) -> Result<&syn::PathSegment> {
+    let syn::Type::Path(type_path) = ty else {
+        bail!("non-path type");
+    };
+
+    type_path
+        .path
+        .segments
+        .last()
+        .ok_or_else(|| anyhow!("empty path"))
+}
+
+fn type_argument(path_arguments: &syn::PathArguments) -> Result<syn::Type> {
+    let syn::PathArguments::AngleBracketed(path_arguments) = path_arguments else {
+        bail!("non angle bracketed arguments")
+    };
+
+    let Some(syn::GenericArgument::Type(ty)) = path_arguments.args.first() else {
+        bail!("type argument is not a type")
+    };
+
+    Ok(ty.clone())
+}
+
+fn field_type_ident(field: &syn::Field) -> Result<TypeIdent> {
+    let path_segment = last_path_segment(&field.ty)?;
+
+    let ident = if path_segment.ident == "Option" {
+        last_path_segment(&type_argument(&path_segment.arguments)?)?
+            .ident
+            .to_string()
+    } else {
+        path_segment.ident.to_string()
+    };
+
+    Ok(ident.into())
+}
+
+fn reference(ident: &TypeIdent) -> String {
+    format!("#/$defs/{}", ident.as_str())
+}
+
+fn describe(schema: &mut Value, attrs: &[syn::Attribute]) {
+    let description = attrs
+        .iter()
+        .filter_map(Attribute::doc_string)
+        .map(|line| line.strip_prefix(' ').map(str::to_owned).unwrap_or(line))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    if !description.is_empty() {
+        schema["description"] = description.into();
+    }
+}
@@ -3,47 +3,210 @@
 #[macro_use]
 mod html;
 
+mod api;
+mod atom;
+mod atom_feed;
+mod calendar_feeds;
 mod components;
 mod constants;
+mod contrast;
+mod diagnostic;
 mod expected_files;
 mod file_specs;
 mod fonts;
 mod google_font;
 mod graphic_file_specs;
+mod ical;
+mod json_schema;
 mod markdown;
 mod mob;
 mod pages;
+mod redirects;
 mod relative_path;
+mod sitemap;
+mod social_preview;
+mod structured_data;
 mod style;
 mod syn_helpers;
 mod tailwind;
 mod url;
+mod xml;
 
-use camino::Utf8PathBuf;
-use clap::Parser;
-use ssg_child::generate_static_site;
+use std::process::ExitCode;
+
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::DateTime;
+use clap::{Parser, Subcommand};
+use const_format::formatcp;
+use constants::DOMAIN;
+use diagnostic::Severity;
+use ssg_child::{generate_static_site, stale_files::StaleFileCleanup};
+use url::Url;
 
 #[derive(Parser)]
 struct Cli {
-    mobs_path: Utf8PathBuf,
-    output_dir: Utf8PathBuf,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Generate the website
+    Build {
+        mobs_path: Utf8PathBuf,
+        output_dir: Utf8PathBuf,
+        /// The people file, whose entries participants may refer to by key
+        #[arg(long, default_value = "people.yaml")]
+        people: Utf8PathBuf,
+        /// How to report text and background colors with insufficient contrast
+        #[arg(long, value_enum, default_value_t = Severity::Warning)]
+        contrast: Severity,
+        /// Unix time of the last change to the mob files, for those without an `updated` date or commits, such as in a nix build
+        #[arg(long)]
+        last_modified: Option<i64>,
+        /// Where the website is served, for the sitemap, the feed and link previews
+        #[arg(long, default_value = formatcp!("https://{DOMAIN}/"))]
+        base_url: Url,
+        /// Whether to list or remove files in the output directory that are no longer generated, which are kept otherwise
+        #[arg(long, value_enum)]
+        stale_files: Option<StaleFiles>,
+        /// Where to write a JSON manifest of the generated files, such as outside the output directory, so that it is not published
+        #[arg(long)]
+        manifest: Option<Utf8PathBuf>,
+    },
+    /// Check the mob files without generating the website
+    Validate {
+        mobs_path: Utf8PathBuf,
+        /// The people file, whose entries participants may refer to by key
+        #[arg(long, default_value = "people.yaml")]
+        people: Utf8PathBuf,
+        /// How to report text and background colors with insufficient contrast
+        #[arg(long, value_enum, default_value_t = Severity::Warning)]
+        contrast: Severity,
+        /// Unix time of the last change to the mob files, for those without an `updated` date or commits, such as in a nix build
+        #[arg(long)]
+        last_modified: Option<i64>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum StaleFiles {
+    List,
+    Remove,
 }
 
 #[tokio::main]
-async fn main() {
-    let Cli {
-        mobs_path,
-        output_dir,
-    } = Cli::parse();
+async fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Build {
+            mobs_path,
+            output_dir,
+            people,
+            contrast,
+            last_modified,
+            base_url,
+            stale_files,
+            manifest,
+        } => {
+            build(
+                &mobs_path,
+                output_dir,
+                &people,
+                contrast,
+                last_modified,
+                &base_url,
+                stale_files,
+                manifest,
+            )
+            .await
+        }
+        Command::Validate {
+            mobs_path,
+            people,
+            contrast,
+            last_modified,
+        } => validate(&mobs_path, &people, contrast, last_modified),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+async fn build(
+    mobs_path: &Utf8Path,
+    output_dir: Utf8PathBuf,
+    people_path: &Utf8Path,
+    contrast: Severity,
+    last_modified: Option<i64>,
+    base_url: &Url,
+    stale_files: Option<StaleFiles>,
+    manifest: Option<Utf8PathBuf>,
+) -> ExitCode {
+    let last_modified = last_modified.and_then(|seconds| DateTime::from_timestamp(seconds, 0));
+
+    let mobs = match mob::get_all(mobs_path, people_path, contrast, last_modified) {
+        Ok((mobs, warnings)) => {
+            if !warnings.is_empty() {
+                eprintln!("{warnings}");
+            }
 
-    let file_specs = file_specs::get(&mobs_path);
-    let mut generation_task = generate_static_site(output_dir.clone(), file_specs);
+            mobs
+        }
+        Err(report) => {
+            eprintln!("{report}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let file_specs = file_specs::get(mobs, base_url).await;
+    let mut generation_task = generate_static_site(output_dir, file_specs);
 
     generation_task.set_file_result_fn(|progress_report| {
         eprintln!("{progress_report:?}");
     });
 
+    if let Some(stale_files) = stale_files {
+        let dry_run = stale_files == StaleFiles::List;
+
+        let stale_file_cleanup =
+            StaleFileCleanup::new::<&str>([], dry_run).with_stale_file_fn(move |path| {
+                if dry_run {
+                    eprintln!("Stale: {path}");
+                } else {
+                    eprintln!("Removing stale: {path}");
+                }
+            });
+
+        generation_task.set_stale_file_cleanup(stale_file_cleanup);
+    }
+
+    if let Some(manifest) = manifest {
+        generation_task.set_manifest_path(manifest);
+    }
+
     generation_task.await.unwrap();
 
-    tailwind::execute(&output_dir).await;
+    ExitCode::SUCCESS
+}
+
+fn validate(
+    mobs_path: &Utf8Path,
+    people_path: &Utf8Path,
+    contrast: Severity,
+    last_modified: Option<i64>,
+) -> ExitCode {
+    let last_modified = last_modified.and_then(|seconds| DateTime::from_timestamp(seconds, 0));
+
+    match mob::get_all(mobs_path, people_path, contrast, last_modified) {
+        Ok((mobs, warnings)) => {
+            if !warnings.is_empty() {
+                eprintln!("{warnings}");
+            }
+
+            eprintln!("{} mob files are valid", mobs.len());
+            ExitCode::SUCCESS
+        }
+        Err(report) => {
+            eprintln!("{report}");
+            ExitCode::FAILURE
+        }
+    }
 }
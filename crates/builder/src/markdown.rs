@@ -6,6 +6,10 @@ use serde::{Deserialize, Serialize};
 pub(crate) struct Markdown(String);
 
 impl Markdown {
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
     fn to_html(&self) -> Markup {
         PreEscaped(markdown_to_html(&self.0, &ComrakOptions::default()))
     }
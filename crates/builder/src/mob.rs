@@ -1,39 +1,49 @@
 pub(super) mod file;
 pub(super) mod id;
-pub(super) mod link;
+pub(crate) mod link;
 pub(super) mod participant;
+pub(crate) mod people;
 pub(super) mod recurring_session;
 pub(crate) mod status;
 pub(super) mod subtitle;
-pub(super) mod title;
+pub(crate) mod tag;
+pub(crate) mod title;
+mod validation;
 
 use std::collections::BTreeSet;
-use std::io;
 
-use anyhow::{anyhow, Context, Result};
-use camino::Utf8Path;
-use chrono::DateTime;
+use anyhow::{Context, Result};
+use camino::{Utf8Path, Utf8PathBuf};
+use chrono::{DateTime, NaiveTime, Utc};
 use csscolorparser::Color;
 use getset::Getters;
+use itertools::Itertools;
 use maud::{html, Markup, Render};
+use serde::de::DeserializeOwned;
 
-use ssg_child::sources::BytesSource;
-use ssg_child::sources::ExpectedFiles;
-use ssg_child::FileSpec;
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
 
+use crate::components::upcoming_sessions::{UpcomingSession, UpcomingSessions};
 use crate::components::{self, CalendarEvent};
+use crate::diagnostic::{Diagnostic, Report, Severity};
 use crate::expected_files::ExpectedFilesExt;
 use crate::markdown::Markdown;
+use crate::pages::PageFile;
 use crate::relative_path::RelativePathBuf;
+use crate::url::Url;
+use crate::{social_preview, structured_data};
 
 pub(crate) use self::file::MobFile;
+pub(crate) use self::file::YamlParticipant;
 pub(crate) use self::file::YamlRecurringSession;
 use self::id::Id;
 pub(crate) use self::link::{Link, LinkElement};
 pub(crate) use self::participant::{Participant, Person};
-use self::recurring_session::RecurringSession;
+use self::people::People;
+pub(crate) use self::recurring_session::RecurringSession;
 pub(crate) use self::status::Status;
 use self::subtitle::Subtitle;
+pub(crate) use self::tag::Tag;
 use self::title::Title;
 
 #[derive(Debug, Clone, Getters)]
@@ -46,62 +56,164 @@ pub struct Mob {
     subtitle: Option<Subtitle>,
     #[getset(get = "pub(crate)")]
     participants: Vec<Participant>,
+    #[getset(get = "pub(crate)")]
     schedule: Vec<RecurringSession>,
     #[getset(get = "pub(crate)")]
     freeform_copy_markdown: Markdown,
+    #[getset(get = "pub(crate)")]
     background_color: Color,
+    #[getset(get = "pub(crate)")]
     text_color: Color,
+    #[getset(get = "pub(crate)")]
     links: Vec<Link>,
     #[getset(get = "pub(crate)")]
+    tags: Vec<Tag>,
+    #[getset(get = "pub(crate)")]
     status: Status,
+    #[getset(get = "pub(crate)")]
+    updated: DateTime<Utc>,
 }
 
-impl TryFrom<(String, MobFile)> for Mob {
+impl TryFrom<(String, MobFile, &People, DateTime<Utc>)> for Mob {
     type Error = anyhow::Error;
-    fn try_from((id, yaml): (String, MobFile)) -> Result<Self, Self::Error> {
+    fn try_from(
+        (id, yaml, people, updated): (String, MobFile, &People, DateTime<Utc>),
+    ) -> Result<Self, Self::Error> {
         Ok(Mob {
             id: Id::new(id),
             title: yaml.title().clone(),
             subtitle: yaml.subtitle().cloned(),
-            participants: yaml.participants().clone(),
+            participants: yaml
+                .participants()
+                .iter()
+                .enumerate()
+                .map(|(index, participant)| {
+                    participant
+                        .resolve(people)
+                        .with_context(|| format!("participants[{index}]"))
+                })
+                .collect::<Result<Vec<_>, _>>()?,
             schedule: yaml
                 .schedule()
                 .cloned()
-                .map(TryInto::try_into)
+                .enumerate()
+                .map(|(index, recurring_session)| {
+                    recurring_session
+                        .try_into()
+                        .with_context(|| format!("schedule[{index}]"))
+                })
                 .collect::<Result<Vec<_>, _>>()?,
             freeform_copy_markdown: yaml.freeform_copy().clone(),
             background_color: yaml.background_color().clone(),
             text_color: yaml.text_color().clone(),
             links: yaml.links().cloned().unwrap_or_default(),
+            tags: yaml.tags().to_vec(),
             status: yaml.status().clone(),
+            updated,
         })
     }
 }
 
-fn read_mob(dir_entry: Result<std::fs::DirEntry, io::Error>) -> Mob {
-    let data_file_path = dir_entry.unwrap().path();
-    let id = data_file_path
-        .file_stem()
-        .context("no filename extension")
-        .unwrap()
-        .to_str()
-        .context("invalid utf8")
-        .unwrap()
-        .into();
+fn read_yaml_file<T: DeserializeOwned>(data_file_path: &Utf8Path) -> Result<T, Diagnostic> {
+    let data = std::fs::read_to_string(data_file_path)
+        .map_err(|error| Diagnostic::error(data_file_path, None, error.to_string()))?;
 
-    let data = std::fs::read_to_string(data_file_path.clone()).unwrap();
+    let deserializer = serde_yaml::Deserializer::from_str(&data);
 
-    let yaml_mob: MobFile = serde_yaml::from_str(&data)
-        .map_err(|e| anyhow!("{:?} {:?}", data_file_path, e))
-        .unwrap();
+    serde_path_to_error::deserialize(deserializer)
+        .map_err(|error| deserialization_diagnostic(data_file_path, error))
+}
+
+fn deserialization_diagnostic(
+    data_file_path: &Utf8Path,
+    error: serde_path_to_error::Error<serde_yaml::Error>,
+) -> Diagnostic {
+    let field = error.path().to_string();
+    let error = error.into_inner();
+    let location = error.location();
+    let message = error.to_string();
+
+    // the location and the field are reported separately
+    let message = location
+        .as_ref()
+        .and_then(|location| {
+            message.strip_suffix(&format!(
+                " at line {} column {}",
+                location.line(),
+                location.column()
+            ))
+        })
+        .unwrap_or(&message);
 
-    (id, yaml_mob).try_into().unwrap()
+    // serde_yaml prefixes the path it knows of, which may be an ancestor of the field
+    let message = message
+        .split_once(": ")
+        .filter(|(path, _)| field.starts_with(path))
+        .map_or(message, |(_, message)| message)
+        .to_owned();
+
+    let field = (field != ".").then_some(field);
+
+    Diagnostic::new(
+        Severity::Error,
+        data_file_path,
+        location.map(Into::into),
+        field,
+        message,
+    )
+}
+
+/// The `updated` field, or else the time of the file's last commit, or else `last_modified`
+fn updated(
+    data_file_path: &Utf8Path,
+    mob_file: &MobFile,
+    last_modified: Option<DateTime<Utc>>,
+) -> Result<DateTime<Utc>, Diagnostic> {
+    if let Some(date) = mob_file.updated() {
+        return Ok(date.and_time(NaiveTime::MIN).and_utc());
+    }
+
+    last_commit_time(data_file_path)
+        .or(last_modified)
+        .ok_or_else(|| {
+            Diagnostic::error(
+                data_file_path,
+                None,
+                "no `updated` date and no commit of the file".to_owned(),
+            )
+        })
+}
+
+/// `None` where the file is not committed or git is not available
+fn last_commit_time(path: &Utf8Path) -> Option<DateTime<Utc>> {
+    let directory = path.parent().filter(|parent| !parent.as_str().is_empty());
+
+    let output = std::process::Command::new("git")
+        .current_dir(directory.unwrap_or(Utf8Path::new(".")))
+        .args(["log", "-1", "--format=%cI", "--"])
+        .arg(path.file_name()?)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let committed = String::from_utf8(output.stdout).ok()?;
+
+    DateTime::parse_from_rfc3339(committed.trim())
+        .ok()
+        .map(|committed| committed.with_timezone(&Utc))
 }
 
 type EventContentTemplate =
     fn(DateTime<rrule::Tz>, DateTime<rrule::Tz>, &Mob, &mut ExpectedFiles) -> Markup;
 
 impl Mob {
+    pub(crate) fn is_active(&self) -> bool {
+        !matches!(self.status, Status::Terminated(_) | Status::Renamed(_))
+    }
+
     pub(crate) fn events(
         &self,
         expected_files: &mut ExpectedFiles,
@@ -112,7 +224,7 @@ impl Mob {
             .iter()
             .map(|recurring_session| {
                 let mob = self.clone();
-                let start = *recurring_session.recurrence().get_dt_start();
+                let start = recurring_session.start();
                 let end = start + recurring_session.duration();
 
                 let event_content = event_content_template(start, end, &mob, expected_files);
@@ -128,8 +240,7 @@ impl Mob {
                 .0;
 
                 CalendarEvent::new(
-                    recurring_session.recurrence().clone(),
-                    recurring_session.duration(),
+                    recurring_session,
                     event_content,
                     background_color,
                     text_color,
@@ -140,9 +251,39 @@ impl Mob {
         events
     }
 
-    pub(super) fn page(self) -> FileSpec {
+    pub(crate) fn next_session(&self, after: DateTime<Utc>) -> Option<DateTime<rrule::Tz>> {
+        self.schedule
+            .iter()
+            .filter_map(|recurring_session| recurring_session.next_occurrence(after))
+            .min()
+    }
+
+    /// With a path, each session links to the mob's page under its title
+    pub(crate) fn upcoming_sessions(
+        &self,
+        as_of: DateTime<Utc>,
+        path: Option<&RelativePathBuf>,
+    ) -> Vec<UpcomingSession> {
+        self.schedule
+            .iter()
+            .flat_map(|recurring_session| {
+                recurring_session
+                    .occurrences(as_of, UpcomingSessions::until(as_of))
+                    .into_iter()
+                    .map(move |start| {
+                        UpcomingSession::new(
+                            start,
+                            start + recurring_session.duration(),
+                            path.map(|path| (self.title.clone(), path.clone())),
+                        )
+                    })
+            })
+            .collect()
+    }
+
+    pub(super) fn page(self, base_url: &Url, fingerprints: &Fingerprints) -> PageFile {
         let path = RelativePathBuf::from(format!("/mobs/{}.html", self.id));
-        let mut expected_files = ExpectedFiles::default();
+        let mut expected_files = ExpectedFiles::new(fingerprints);
 
         let links = self
             .links
@@ -157,7 +298,7 @@ impl Mob {
         );
 
         let markup = if let status::Status::Renamed(renamed_id) = self.status() {
-            let base = components::PageBase::new(&mut expected_files, path.clone());
+            let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
 
             let page = components::redirect_page::RedirectPage::new(
                 base,
@@ -166,7 +307,41 @@ impl Mob {
 
             page.render()
         } else {
-            let base = components::PageBase::new(&mut expected_files, path.clone());
+            let base = components::PageBase::new(&mut expected_files, path.clone(), base_url)
+                .with_social_image(expected_files.insert_(social_preview::mob_path(&self)));
+            let calendar_feed_path = expected_files.insert_(format!("/mobs/{}.ics", self.id));
+            let as_of = Utc::now();
+            let upcoming_sessions =
+                UpcomingSessions::new(as_of, self.upcoming_sessions(as_of, None));
+
+            let tags = self
+                .tags
+                .iter()
+                .map(|tag| {
+                    (
+                        tag.clone(),
+                        expected_files.insert_(format!("/tags/{tag}.html")),
+                    )
+                })
+                .collect();
+
+            let participants = self
+                .participants
+                .iter()
+                .map(|participant| {
+                    let person_page_path = match participant {
+                        Participant::Hidden => None,
+                        Participant::Public(person) => Some(
+                            expected_files
+                                .insert_(format!("/people/{}.html", person.name().slug())),
+                        ),
+                    };
+
+                    (participant.clone(), person_page_path)
+                })
+                .collect();
+
+            let structured_data = structured_data::event_series(&self, as_of, base_url);
 
             let page = components::mob_page::MobPage::new(
                 self,
@@ -176,19 +351,80 @@ impl Mob {
                 expected_files.insert_("/fullcalendar.js"),
                 expected_files.insert_("/rrule.js"),
                 expected_files.insert_("/fullcalendar_rrule.js"),
+                calendar_feed_path,
+                upcoming_sessions,
+                tags,
+                participants,
+                structured_data,
             );
 
             page.render()
         };
 
-        let bytes = markup.render().0.into_bytes();
-
-        FileSpec::new(path, BytesSource::new(bytes, Some(expected_files)))
+        PageFile::new(path, markup, expected_files)
     }
 }
 
-pub(crate) fn get_all(mobs_path: &Utf8Path) -> impl IntoIterator<Item = Mob> {
-    std::fs::read_dir(mobs_path).unwrap().map(read_mob)
+/// On success, the report contains only warnings
+pub(crate) fn get_all(
+    mobs_path: &Utf8Path,
+    people_path: &Utf8Path,
+    contrast_severity: Severity,
+    last_modified: Option<DateTime<Utc>>,
+) -> Result<(Vec<Mob>, Report), Report> {
+    let people = read_yaml_file::<People>(people_path)?;
+
+    let data_file_paths = mobs_path
+        .read_dir_utf8()
+        .and_then(Iterator::collect::<Result<Vec<_>, _>>)
+        .map_err(|error| Diagnostic::error(mobs_path, None, error.to_string()))?
+        .into_iter()
+        .map(camino::Utf8DirEntry::into_path)
+        .collect::<Vec<Utf8PathBuf>>();
+
+    let ids = data_file_paths
+        .iter()
+        .filter_map(|data_file_path| data_file_path.file_stem())
+        .collect::<BTreeSet<&str>>();
+
+    let (mob_files, mut report): (Vec<(&Utf8Path, MobFile)>, Report) = data_file_paths
+        .iter()
+        .map(|data_file_path| Ok((data_file_path.as_path(), read_yaml_file(data_file_path)?)))
+        .partition_result();
+
+    report.extend(mob_files.iter().flat_map(|(data_file_path, mob_file)| {
+        validation::validate(data_file_path, mob_file, &ids, &people, contrast_severity)
+    }));
+
+    if report.has_errors() {
+        return Err(report);
+    }
+
+    let (mobs, conversion_report): (Vec<Mob>, Report) = mob_files
+        .into_iter()
+        .map(|(data_file_path, mob_file)| {
+            let id = data_file_path
+                .file_stem()
+                .ok_or_else(|| Diagnostic::error(data_file_path, None, "no file name".to_owned()))?
+                .to_owned();
+
+            let updated = updated(data_file_path, &mob_file, last_modified)?;
+
+            (id, mob_file, &people, updated)
+                .try_into()
+                .map_err(|error: anyhow::Error| {
+                    Diagnostic::error(data_file_path, None, format!("{error:#}"))
+                })
+        })
+        .partition_result();
+
+    report.extend(conversion_report);
+
+    if report.has_errors() {
+        Err(report)
+    } else {
+        Ok((mobs, report))
+    }
 }
 
 pub(crate) fn get_all_participants(mobs: &[Mob]) -> BTreeSet<Person> {
@@ -1,16 +1,20 @@
+pub(crate) mod yaml_participant;
 pub(crate) mod yaml_recurring_session;
 
+use chrono::NaiveDate;
 use csscolorparser::Color;
 use schema::Schema;
 use serde::Deserialize;
 
 use crate::markdown::Markdown;
 
+pub(crate) use self::yaml_participant::{PersonKey, YamlParticipant};
 pub(crate) use self::yaml_recurring_session::YamlRecurringSession;
 
-use super::{subtitle::Subtitle, title::Title, Link, Participant, Status};
+use super::{subtitle::Subtitle, tag::Tag, title::Title, Link, Status};
 
 #[derive(Deserialize, Schema)]
+#[serde(deny_unknown_fields)]
 /// The contents of a mob file
 pub(crate) struct MobFile {
     /// The mob's title
@@ -30,7 +34,7 @@ pub(crate) struct MobFile {
     /// ```
     subtitle: Option<Subtitle>,
     /// Regular participants of the mob
-    participants: Vec<Participant>,
+    participants: Vec<YamlParticipant>,
     /// The mob's regular schedule
     schedule: Vec<YamlRecurringSession>,
     /// Color of the background of calendar event blocks
@@ -63,6 +67,15 @@ pub(crate) struct MobFile {
     /// We study the BrainShock programming language.
     /// ```
     freeform_copy: Markdown,
+    /// Topics of the mob, in lowercase letters, digits and hyphens
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// - rust
+    /// - game-dev
+    /// ```
+    tags: Option<Vec<Tag>>,
     /// The mob's current status
     ///
     /// Example:
@@ -74,6 +87,16 @@ pub(crate) struct MobFile {
     ///   [Room link](https://meet.jit.si/MedievalWebsPortrayLoud)
     /// ```
     status: Status,
+    /// When the mob's status or description last changed, for the feed of mobs
+    ///
+    /// Defaults to the time of the last commit of the mob file.
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// 2023-06-21
+    /// ```
+    updated: Option<NaiveDate>,
 }
 
 impl MobFile {
@@ -85,7 +108,7 @@ impl MobFile {
         self.subtitle.as_ref()
     }
 
-    pub(crate) fn participants(&self) -> &Vec<Participant> {
+    pub(crate) fn participants(&self) -> &Vec<YamlParticipant> {
         &self.participants
     }
 
@@ -109,7 +132,15 @@ impl MobFile {
         self.links.as_ref()
     }
 
+    pub(crate) fn tags(&self) -> &[Tag] {
+        self.tags.as_deref().unwrap_or_default()
+    }
+
     pub(crate) fn status(&self) -> &Status {
         &self.status
     }
+
+    pub(crate) fn updated(&self) -> Option<NaiveDate> {
+        self.updated
+    }
 }
@@ -0,0 +1,54 @@
+use anyhow::{anyhow, Result};
+use schema::Schema;
+use serde::Deserialize;
+
+use crate::mob::{people::People, Participant, Person};
+
+#[derive(Debug, Clone, Deserialize, Schema)]
+/// A participant in a mob
+pub(crate) enum YamlParticipant {
+    /// A mob member who prefers to remain anonymous
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Hidden
+    /// ```
+    Hidden,
+    /// A mob member who wishes to be publically listed
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Public
+    /// name: Forbany Klenbin
+    /// social_url: https://example.com/fk
+    /// avatar_url: https://example.com/fk.png
+    /// ```
+    Public(Person),
+    /// A mob member from the people file, by their key
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Ref forbany
+    /// ```
+    Ref(PersonKey),
+}
+
+impl YamlParticipant {
+    pub(crate) fn resolve(&self, people: &People) -> Result<Participant> {
+        match self {
+            YamlParticipant::Hidden => Ok(Participant::Hidden),
+            YamlParticipant::Public(person) => Ok(Participant::Public(person.clone())),
+            YamlParticipant::Ref(key) => people
+                .get(key)
+                .cloned()
+                .map(Participant::Public)
+                .ok_or_else(|| anyhow!("there is no person `{key}` in the people file")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Deserialize, derive_more::Display)]
+pub(crate) struct PersonKey(String);
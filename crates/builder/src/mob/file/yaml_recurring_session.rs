@@ -1,10 +1,17 @@
-use chrono::{Duration, NaiveDate};
+use std::fmt;
+
+use chrono::{Duration, NaiveDate, NaiveTime};
 use chrono_tz::Tz;
 use getset::{CopyGetters, Getters};
+use rrule::{RRule, Unvalidated};
 use schema::Schema;
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize,
+};
 
 #[derive(Deserialize, Schema, Clone, Getters, CopyGetters)]
+#[serde(deny_unknown_fields)]
 /// Specification for a recurring session
 pub(crate) struct YamlRecurringSession {
     /// Frequency of the recurrence in [RRULE](https://icalendar.org/iCalendar-RFC-5545/3-8-5-3-recurrence-rule.html) format
@@ -14,8 +21,10 @@ pub(crate) struct YamlRecurringSession {
     /// ```yaml
     /// FREQ=WEEKLY;BYDAY=MO,TU,WE,TH
     /// ```
+    ///
+    /// Omit for a single session on the start date.
     #[getset(get = "pub(crate)")]
-    frequency: RecurrenceFrequency,
+    frequency: Option<RecurrenceFrequency>,
     /// The schedule's timezone
     ///
     /// Example:
@@ -41,7 +50,7 @@ pub(crate) struct YamlRecurringSession {
     /// ```yaml
     /// 04:00
     /// ```
-    #[getset(get = "pub(crate)")]
+    #[getset(get_copy = "pub(crate)")]
     start_time: Time,
     /// Session duration in minutes
     ///
@@ -52,9 +61,83 @@ pub(crate) struct YamlRecurringSession {
     /// ```
     #[getset(get_copy = "pub(crate)")]
     duration: Minutes,
+    /// Dates of sessions in addition to the recurrence, at the same time
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// - 2023-03-04
+    /// ```
+    additional_dates: Option<Vec<NaiveDate>>,
+    /// Dates of sessions of the recurrence that are cancelled
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// - 2023-12-25
+    /// - 2024-01-01
+    /// ```
+    cancelled_dates: Option<Vec<NaiveDate>>,
+}
+
+impl YamlRecurringSession {
+    pub(crate) fn additional_dates(&self) -> &[NaiveDate] {
+        self.additional_dates.as_deref().unwrap_or_default()
+    }
+
+    pub(crate) fn cancelled_dates(&self) -> &[NaiveDate] {
+        self.cancelled_dates.as_deref().unwrap_or_default()
+    }
+}
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(into = "String")]
+pub(crate) struct Time(NaiveTime);
+
+// the conversion is in the visitor, so that an error is reported at the value rather than at its parent
+impl<'de> Deserialize<'de> for Time {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct TimeVisitor;
+
+        impl Visitor<'_> for TimeVisitor {
+            type Value = Time;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("a time in the format HH:MM")
+            }
+
+            fn visit_str<E: de::Error>(self, time: &str) -> Result<Self::Value, E> {
+                Time::try_from(time.to_owned()).map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(TimeVisitor)
+    }
+}
+
+impl TryFrom<String> for Time {
+    type Error = String;
+
+    fn try_from(time: String) -> Result<Self, Self::Error> {
+        // chrono also accepts a single digit hour
+        NaiveTime::parse_from_str(&time, "%R")
+            .ok()
+            .filter(|_| time.len() == "HH:MM".len())
+            .map(Self)
+            .ok_or_else(|| format!("`{time}` is not a time in the format HH:MM"))
+    }
+}
+
+impl From<Time> for String {
+    fn from(time: Time) -> Self {
+        time.0.format("%R").to_string()
+    }
+}
+
+impl From<Time> for NaiveTime {
+    fn from(time: Time) -> Self {
+        time.0
+    }
 }
-#[derive(Debug, Clone, Serialize, Deserialize, derive_more::Display)]
-pub(crate) struct Time(String);
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub(crate) struct Minutes(u16);
@@ -65,5 +148,32 @@ impl From<Minutes> for Duration {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, derive_more::Display)]
-pub(crate) struct RecurrenceFrequency(String);
+/// Displays as written, because rrule's formatting differs from it (e.g. `FREQ=weekly`)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, derive_more::Display)]
+#[serde(try_from = "String", into = "String")]
+#[display(fmt = "{source}")]
+pub(crate) struct RecurrenceFrequency {
+    source: String,
+    rrule: RRule<Unvalidated>,
+}
+
+impl TryFrom<String> for RecurrenceFrequency {
+    type Error = rrule::RRuleError;
+
+    fn try_from(source: String) -> Result<Self, Self::Error> {
+        let rrule = source.parse()?;
+        Ok(Self { source, rrule })
+    }
+}
+
+impl From<RecurrenceFrequency> for String {
+    fn from(frequency: RecurrenceFrequency) -> Self {
+        frequency.source
+    }
+}
+
+impl From<RecurrenceFrequency> for RRule<Unvalidated> {
+    fn from(frequency: RecurrenceFrequency) -> Self {
+        frequency.rrule
+    }
+}
@@ -7,4 +7,8 @@ impl Id {
     pub(super) fn new(id: String) -> Self {
         Self(id)
     }
+
+    pub(crate) fn as_str(&self) -> &str {
+        &self.0
+    }
 }
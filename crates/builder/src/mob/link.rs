@@ -16,47 +16,243 @@ pub(crate) enum Link {
     /// ```yaml
     /// !YouTube "@mobseattle"
     /// ```
-    YouTube(String),
+    YouTube(Handle),
+    #[allow(clippy::doc_markdown)]
+    /// A GitHub user, organization or repository
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !GitHub mobusoperandi/website
+    /// ```
+    GitHub(Handle),
+    /// A Twitch channel name
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Twitch mobseattle
+    /// ```
+    Twitch(Handle),
+    /// A Discord invite code
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Discord Xb4Qp7Ux
+    /// ```
+    Discord(Handle),
+    /// A Zulip stream URL
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Zulip https://mobusoperandi.zulipchat.com/#narrow/stream/390231-mob-pangolin
+    /// ```
+    Zulip(Url),
+    /// A Mastodon account
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Mastodon "@mobseattle@fosstodon.org"
+    /// ```
+    Mastodon(MastodonAccount),
+    /// A blog URL
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Blog https://example.com/blog
+    /// ```
+    Blog(Url),
+    /// Any other website
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// !Website
+    /// url: https://example.com
+    /// label: Our kata collection
+    /// ```
+    Website(Website),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Schema)]
+#[serde(deny_unknown_fields)]
+/// A website with a label for it
+pub(crate) struct Website {
+    /// The website's URL
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// https://example.com
+    /// ```
+    url: Url,
+    /// What the website is
+    ///
+    /// Example:
+    ///
+    /// ```yaml
+    /// Our kata collection
+    /// ```
+    label: String,
+}
+
+/// A channel, user, repository or invite on a site, which goes into the path of a URL of it
+#[derive(Debug, Clone, derive_more::Display, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub(crate) struct Handle(String);
+
+impl TryFrom<String> for Handle {
+    type Error = String;
+
+    fn try_from(handle: String) -> Result<Self, Self::Error> {
+        let is_valid = !handle.is_empty()
+            && !handle.contains(|char: char| {
+                char.is_whitespace() || char.is_control() || matches!(char, '?' | '#' | '\\')
+            })
+            && handle
+                .split('/')
+                .all(|segment| !matches!(segment, "" | "." | ".."));
+
+        if is_valid {
+            Ok(Self(handle))
+        } else {
+            Err(format!("`{handle}` is not a valid handle"))
+        }
+    }
+}
+
+impl From<Handle> for String {
+    fn from(handle: Handle) -> Self {
+        handle.0
+    }
+}
+
+#[derive(Debug, Clone, derive_more::Display, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+#[display(fmt = "@{user}@{instance}")]
+pub(crate) struct MastodonAccount {
+    user: String,
+    instance: String,
+}
+
+impl TryFrom<String> for MastodonAccount {
+    type Error = String;
+
+    fn try_from(account: String) -> Result<Self, Self::Error> {
+        let is_part = |part: &str| {
+            !part.is_empty() && !part.contains(|char: char| char == '/' || char.is_whitespace())
+        };
+
+        account
+            .strip_prefix('@')
+            .and_then(|account| account.split_once('@'))
+            .filter(|(user, instance)| is_part(user) && is_part(instance))
+            .map(|(user, instance)| Self {
+                user: user.to_owned(),
+                instance: instance.to_owned(),
+            })
+            // the instance must be a host alone, such that the profile URL is valid
+            .filter(|account| {
+                url::Url::parse(&format!("https://{}/", account.instance)).is_ok_and(|url| {
+                    url.username().is_empty()
+                        && url.port().is_none()
+                        && url.path() == "/"
+                        && url.query().is_none()
+                        && url.fragment().is_none()
+                })
+            })
+            .ok_or_else(|| format!("`{account}` is not a Mastodon account like `@user@instance`"))
+    }
+}
+
+impl MastodonAccount {
+    fn url(&self) -> Url {
+        let Self { user, instance } = self;
+        Url::parse(&format!("https://{instance}/@{user}"))
+            .expect("the instance is validated to be a host")
+    }
+}
+
+impl From<MastodonAccount> for String {
+    fn from(account: MastodonAccount) -> Self {
+        account.to_string()
+    }
+}
+
+impl Link {
+    /// Handles and accounts are validated on deserialization, so that their URLs are valid
+    pub(crate) fn url(&self) -> Url {
+        let site_url = |site: &str, path: &Handle| {
+            Url::parse(&format!("https://{site}/{path}")).expect("a handle is valid in a URL path")
+        };
+
+        match self {
+            Link::YouTube(channel) => site_url("www.youtube.com", channel),
+            Link::GitHub(path) => site_url("github.com", path),
+            Link::Twitch(channel) => site_url("www.twitch.tv", channel),
+            Link::Discord(invite) => site_url("discord.gg", invite),
+            Link::Mastodon(account) => account.url(),
+            Link::Zulip(url) | Link::Blog(url) | Link::Website(Website { url, .. }) => url.clone(),
+        }
+    }
 }
 
 impl From<(Link, &mut ExpectedFiles)> for LinkElement {
     fn from((link, expected_files): (Link, &mut ExpectedFiles)) -> Self {
-        let (url, image_path, alt) = match link {
-            Link::YouTube(path) => {
-                let url = Url::parse(&format!("https://www.youtube.com/{path}")).unwrap();
-                let image_path = expected_files.insert_("/youtube_logo.svg");
-                let alt = "YouTube";
-                (url, image_path, alt)
-            }
+        let url = link.url();
+
+        // the black icons are inverted to show on the dark background
+        let (image_path, alt, invert) = match link {
+            Link::YouTube(_) => ("/youtube_logo.svg", "YouTube".to_owned(), false),
+            Link::GitHub(_) => ("/inverticat.svg", "GitHub".to_owned(), true),
+            Link::Twitch(_) => ("/twitch_logo.svg", "Twitch".to_owned(), true),
+            Link::Discord(_) => ("/discord_logo.svg", "Discord".to_owned(), true),
+            Link::Zulip(_) => ("/zulip_logo.svg", "Zulip".to_owned(), false),
+            Link::Mastodon(_) => ("/mastodon_logo.svg", "Mastodon".to_owned(), true),
+            Link::Blog(_) => ("/rss_icon.svg", "Blog".to_owned(), true),
+            Link::Website(Website { label, .. }) => ("/globe_icon.svg", label, true),
         };
 
-        LinkElement::new(url, alt, image_path)
+        LinkElement::new(url, alt, expected_files.insert_(image_path), invert)
     }
 }
 
 pub(crate) struct LinkElement {
     url: Url,
-    alt: &'static str,
+    alt: String,
     image_path: RelativePathBuf,
+    invert: bool,
 }
 
 impl LinkElement {
-    fn new(url: Url, alt: &'static str, image_path: RelativePathBuf) -> Self {
+    fn new(url: Url, alt: String, image_path: RelativePathBuf, invert: bool) -> Self {
         Self {
             url,
             alt,
             image_path,
+            invert,
         }
     }
 }
 
 impl Render for LinkElement {
     fn render(&self) -> maud::Markup {
+        let mut classes = classes!("h-8");
+
+        if self.invert {
+            classes.push("invert".parse().unwrap());
+        }
+
         html! {
             a href=(self.url) {
                 img
-                    class=(classes!("h-8"))
+                    class=(classes)
                     alt=(self.alt)
+                    title=(self.alt)
                     src=(self.image_path);
             }
         }
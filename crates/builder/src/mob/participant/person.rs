@@ -6,6 +6,7 @@ use serde::{Deserialize, Serialize};
 use crate::url::Url;
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Schema, Getters)]
+#[serde(deny_unknown_fields)]
 /// The public details about a person
 pub(crate) struct Person {
     /// The person's name
@@ -37,11 +38,38 @@ pub(crate) struct Person {
     avatar_url: Option<Url>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(
+    Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, derive_more::Display,
+)]
 pub(crate) struct PersonName(String);
 
+impl PersonName {
+    /// The name in lowercase, with each run of other than letters and digits replaced by a hyphen,
+    /// for the address of the person's page
+    pub(crate) fn slug(&self) -> String {
+        self.0
+            .split(|char: char| !char.is_alphanumeric())
+            .filter(|word| !word.is_empty())
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join("-")
+    }
+}
+
 impl Render for PersonName {
     fn render(&self) -> Markup {
         self.0.render()
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::PersonName;
+
+    #[test]
+    fn slug() {
+        let name = PersonName("  Zoë O'Brien-Łukasz ".to_owned());
+
+        assert_eq!(name.slug(), "zoë-o-brien-łukasz");
+    }
+}
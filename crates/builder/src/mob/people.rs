@@ -0,0 +1,25 @@
+use std::collections::BTreeMap;
+
+use serde::Deserialize;
+
+use super::{file::PersonKey, Person};
+
+/// The contents of the people file: people that participants may refer to by key, so that
+/// their details are not repeated across mob files
+///
+/// Example:
+///
+/// ```yaml
+/// forbany:
+///   name: Forbany Klenbin
+///   social_url: https://example.com/fk
+///   avatar_url: https://example.com/fk.png
+/// ```
+#[derive(Debug, Default, Deserialize)]
+pub(crate) struct People(BTreeMap<PersonKey, Person>);
+
+impl People {
+    pub(crate) fn get(&self, key: &PersonKey) -> Option<&Person> {
+        self.0.get(key)
+    }
+}
@@ -1,50 +1,120 @@
-use chrono::{Duration, NaiveDateTime};
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use getset::{CopyGetters, Getters};
 use rrule::{RRule, RRuleSet, Unvalidated};
 
-use super::file::yaml_recurring_session::YamlRecurringSession;
+use super::file::yaml_recurring_session::{RecurrenceFrequency, YamlRecurringSession};
 
 #[derive(Debug, Clone, PartialEq, Eq, Getters, CopyGetters)]
 pub(crate) struct RecurringSession {
+    /// In the schedule's own timezone, so that occurrences keep their local time across DST
     #[getset(get = "pub(crate)")]
     recurrence: RRuleSet,
     #[getset(get_copy = "pub(crate)")]
     duration: Duration,
+    /// `None` for a single session
+    #[getset(get = "pub(crate)")]
+    frequency: Option<RecurrenceFrequency>,
+    /// In the schedule's own timezone
+    #[getset(get_copy = "pub(crate)")]
+    start: DateTime<rrule::Tz>,
+    /// In the schedule's own timezone
+    #[getset(get = "pub(crate)")]
+    additional_starts: Vec<DateTime<rrule::Tz>>,
+    /// In the schedule's own timezone
+    #[getset(get = "pub(crate)")]
+    cancelled_starts: Vec<DateTime<rrule::Tz>>,
 }
 
 impl TryFrom<YamlRecurringSession> for RecurringSession {
     type Error = anyhow::Error;
     fn try_from(yaml_recurring_session: YamlRecurringSession) -> Result<Self, Self::Error> {
-        let recurrence = yaml_recurring_session.frequency();
-        let timezone = yaml_recurring_session.timezone();
-        let start_date = yaml_recurring_session.start_date();
-        let start_time = yaml_recurring_session.start_time();
+        let frequency = yaml_recurring_session.frequency().clone();
+        let timezone: rrule::Tz = yaml_recurring_session.timezone().into();
+        let start_time = yaml_recurring_session.start_time().into();
         let duration = yaml_recurring_session.duration();
 
-        let recurrence = format!("RRULE:{recurrence}");
-        let rrule: RRule<Unvalidated> = recurrence.parse()?;
-        let timezone: rrule::Tz = timezone.into();
-
-        let start_date_time =
-            NaiveDateTime::parse_from_str(&format!("{start_date}{start_time}"), "%F%R")?
-                .and_local_timezone(timezone)
-                .unwrap()
-                // workaround for https://github.com/fullcalendar/fullcalendar/issues/6815
-                // timezones with non-zero offset result in occurrences with wrong datetimes
-                .with_timezone(&rrule::Tz::UTC);
-
-        let recurrence = rrule
-            // workaround for https://github.com/fullcalendar/fullcalendar/issues/6834
-            // no ocurrences generated for recurring events with TZID and without UNTIL
-            // so we add an arbitrary UNTIL
-            .until((start_date_time + Duration::days(365 * 99)).with_timezone(&rrule::Tz::UTC))
-            .build(start_date_time)?;
+        let local_starts = |dates: &[NaiveDate]| {
+            dates
+                .iter()
+                .map(|&date| local_start(date, start_time, timezone))
+                .collect::<Result<Vec<_>>>()
+        };
+
+        let start = local_start(yaml_recurring_session.start_date(), start_time, timezone)?;
+        let additional_starts = local_starts(yaml_recurring_session.additional_dates())?;
+        let cancelled_starts = local_starts(yaml_recurring_session.cancelled_dates())?;
+
+        let recurrence = match &frequency {
+            Some(frequency) => {
+                let rrule: RRule<Unvalidated> = frequency.clone().into();
+                rrule.build(start)?
+            }
+            None => RRuleSet::new(start).rdate(start),
+        };
+
+        let recurrence = additional_starts
+            .iter()
+            .copied()
+            .fold(recurrence, RRuleSet::rdate);
+
+        let recurrence = cancelled_starts
+            .iter()
+            .copied()
+            .fold(recurrence, RRuleSet::exdate);
 
         let duration = duration.into();
 
         Ok(RecurringSession {
             recurrence,
             duration,
+            frequency,
+            start,
+            additional_starts,
+            cancelled_starts,
         })
     }
 }
+
+impl RecurringSession {
+    /// Starts of the sessions in the given range, in the schedule's own timezone
+    pub(crate) fn occurrences(
+        &self,
+        after: DateTime<Utc>,
+        before: DateTime<Utc>,
+    ) -> Vec<DateTime<rrule::Tz>> {
+        let timezone = self.start.timezone();
+
+        let (occurrences, _) = self
+            .recurrence
+            .clone()
+            .after(after.with_timezone(&timezone))
+            .before(before.with_timezone(&timezone))
+            .all(u16::MAX);
+
+        occurrences
+    }
+
+    pub(crate) fn next_occurrence(&self, after: DateTime<Utc>) -> Option<DateTime<rrule::Tz>> {
+        let (occurrences, _) = self
+            .recurrence
+            .clone()
+            .after(after.with_timezone(&self.start.timezone()))
+            .all(1);
+
+        occurrences.into_iter().next()
+    }
+}
+
+fn local_start(
+    date: NaiveDate,
+    time: NaiveTime,
+    timezone: rrule::Tz,
+) -> Result<DateTime<rrule::Tz>> {
+    let start = NaiveDateTime::new(date, time);
+
+    start
+        .and_local_timezone(timezone)
+        .earliest()
+        .ok_or_else(|| anyhow!("{start} does not exist in {}", timezone.name()))
+}
@@ -87,6 +87,16 @@ pub(crate) enum Status {
 }
 
 impl Status {
+    pub(crate) fn content(&self) -> Option<&Markdown> {
+        match self {
+            Status::Short(content) | Status::Open(content) | Status::Public(content) => {
+                Some(content)
+            }
+            Status::Full(content) | Status::Terminated(content) => content.as_ref(),
+            Status::Renamed(_) => None,
+        }
+    }
+
     pub(crate) fn description(variant_ident: &str) -> Description {
         let syn::Data::Enum(enum_data) = Self::schema().data else {
             panic!("not an enum??")
@@ -173,7 +183,7 @@ impl Render for StatusIndicator {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, derive_more::Display)]
 pub(crate) struct Description(String);
 
 impl Render for Description {
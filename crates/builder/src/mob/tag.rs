@@ -0,0 +1,40 @@
+use maud::{Markup, Render};
+use serde::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, derive_more::Display, Serialize, Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub(crate) struct Tag(String);
+
+// tags are used in paths
+impl TryFrom<String> for Tag {
+    type Error = String;
+
+    fn try_from(tag: String) -> Result<Self, Self::Error> {
+        let is_valid = !tag.is_empty()
+            && tag
+                .chars()
+                .all(|char| char.is_ascii_lowercase() || char.is_ascii_digit() || char == '-');
+
+        if is_valid {
+            Ok(Self(tag))
+        } else {
+            Err(format!(
+                "`{tag}` is not a tag; only lowercase letters, digits and hyphens are allowed"
+            ))
+        }
+    }
+}
+
+impl From<Tag> for String {
+    fn from(tag: Tag) -> Self {
+        tag.0
+    }
+}
+
+impl Render for Tag {
+    fn render(&self) -> Markup {
+        self.0.render()
+    }
+}
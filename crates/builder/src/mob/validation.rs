@@ -0,0 +1,216 @@
+use std::collections::BTreeSet;
+
+use camino::Utf8Path;
+use chrono::{Duration, LocalResult, NaiveDateTime};
+use rrule::{RRule, Unvalidated};
+
+use crate::{
+    contrast,
+    diagnostic::{Diagnostic, Report, Severity},
+};
+
+use super::{people::People, MobFile, Participant, Status, YamlRecurringSession};
+
+/// Checks the rules that deserialization alone does not enforce
+///
+/// `ids` are the ids of all the mob files, including ones that failed to deserialize.
+/// `people` are those that participants may refer to.
+/// Insufficient contrast is reported with `contrast_severity`.
+pub(super) fn validate(
+    data_file_path: &Utf8Path,
+    mob_file: &MobFile,
+    ids: &BTreeSet<&str>,
+    people: &People,
+    contrast_severity: Severity,
+) -> Report {
+    let mut report = Report::default();
+
+    if let Status::Renamed(renamed_id) = mob_file.status() {
+        let renamed_id = renamed_id.to_string();
+
+        let message = if data_file_path.file_stem() == Some(renamed_id.as_str()) {
+            Some(format!("renamed to itself, `{renamed_id}`"))
+        } else if ids.contains(renamed_id.as_str()) {
+            None
+        } else {
+            Some(format!("renamed to `{renamed_id}`, which has no mob file"))
+        };
+
+        report.extend(
+            message.map(|message| {
+                Diagnostic::error(data_file_path, Some("status".to_owned()), message)
+            }),
+        );
+    }
+
+    for (index, recurring_session) in mob_file.schedule().enumerate() {
+        report.extend(
+            recurring_session_diagnostics(recurring_session).map(|(field, message)| {
+                Diagnostic::error(
+                    data_file_path,
+                    Some(format!("schedule[{index}].{field}")),
+                    message,
+                )
+            }),
+        );
+    }
+
+    let mut participant_names = BTreeSet::new();
+
+    for (index, participant) in mob_file.participants().iter().enumerate() {
+        let person = match participant.resolve(people) {
+            Ok(Participant::Public(person)) => person,
+            Ok(Participant::Hidden) => continue,
+            Err(error) => {
+                report.extend([Diagnostic::error(
+                    data_file_path,
+                    Some(format!("participants[{index}]")),
+                    error.to_string(),
+                )]);
+
+                continue;
+            }
+        };
+
+        if !participant_names.insert(person.name().clone()) {
+            report.extend([Diagnostic::error(
+                data_file_path,
+                Some(format!("participants[{index}]")),
+                format!("`{}` is listed more than once", person.name()),
+            )]);
+        }
+
+        if person.name().slug().is_empty() {
+            report.extend([Diagnostic::error(
+                data_file_path,
+                Some(format!("participants[{index}].name")),
+                format!("`{}` has no letters or digits", person.name()),
+            )]);
+        }
+    }
+
+    let mut tags = BTreeSet::new();
+
+    for (index, tag) in mob_file.tags().iter().enumerate() {
+        if !tags.insert(tag) {
+            report.extend([Diagnostic::error(
+                data_file_path,
+                Some(format!("tags[{index}]")),
+                format!("`{tag}` is listed more than once"),
+            )]);
+        }
+    }
+
+    let background_color = mob_file.background_color();
+    let text_color = mob_file.text_color();
+    let contrast_ratio = contrast::ratio(background_color, text_color);
+
+    if contrast_ratio < contrast::AA_MINIMUM_RATIO {
+        let suggestion = contrast::nearest_passing_text_color(text_color, background_color);
+
+        report.extend([Diagnostic::new(
+            contrast_severity,
+            data_file_path,
+            None,
+            Some("text_color".to_owned()),
+            format!(
+                "contrast ratio with `background_color` is {contrast_ratio:.2}:1, \
+                 below the WCAG AA minimum of {}:1; \
+                 the nearest passing color is `{}`",
+                contrast::AA_MINIMUM_RATIO,
+                suggestion.to_hex_string()
+            ),
+        )]);
+    }
+
+    report
+}
+
+/// Returns the field and the message of each problem
+fn recurring_session_diagnostics(
+    recurring_session: &YamlRecurringSession,
+) -> impl Iterator<Item = (String, String)> {
+    let mut diagnostics = vec![];
+
+    if Duration::from(recurring_session.duration()) == Duration::zero() {
+        diagnostics.push((
+            "duration".to_owned(),
+            "must be more than zero minutes".to_owned(),
+        ));
+    }
+
+    let timezone: rrule::Tz = recurring_session.timezone().into();
+    let start_time = recurring_session.start_time().into();
+
+    let mut local_start = |field: String, date| {
+        let start = NaiveDateTime::new(date, start_time);
+
+        match start.and_local_timezone(timezone) {
+            LocalResult::Single(start) | LocalResult::Ambiguous(start, _) => Some(start),
+            LocalResult::None => {
+                diagnostics.push((
+                    field,
+                    format!("{start} does not exist in {}", timezone.name()),
+                ));
+                None
+            }
+        }
+    };
+
+    let start = local_start("start_time".to_owned(), recurring_session.start_date());
+
+    for (index, &date) in recurring_session.additional_dates().iter().enumerate() {
+        local_start(format!("additional_dates[{index}]"), date);
+    }
+
+    let cancelled_starts = recurring_session
+        .cancelled_dates()
+        .iter()
+        .enumerate()
+        .filter_map(|(index, &date)| {
+            let field = format!("cancelled_dates[{index}]");
+            Some((field.clone(), local_start(field, date)?))
+        })
+        .collect::<Vec<_>>();
+
+    let recurrence = match (recurring_session.frequency(), start) {
+        (Some(frequency), Some(start)) => {
+            let rrule: RRule<Unvalidated> = frequency.clone().into();
+
+            match rrule.build(start) {
+                Ok(recurrence) => Some(recurrence),
+                Err(error) => {
+                    diagnostics.push(("frequency".to_owned(), error.to_string()));
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    if recurring_session.frequency().is_none() && !cancelled_starts.is_empty() {
+        diagnostics.push((
+            "cancelled_dates".to_owned(),
+            "a single session has no recurrence to cancel sessions of".to_owned(),
+        ));
+    }
+
+    if let Some(recurrence) = recurrence {
+        for (field, cancelled_start) in cancelled_starts {
+            let (occurrences, _) = recurrence
+                .clone()
+                .after(cancelled_start - Duration::minutes(1))
+                .before(cancelled_start + Duration::minutes(1))
+                .all(1);
+
+            if occurrences.is_empty() {
+                diagnostics.push((
+                    field,
+                    format!("there is no session at {}", cancelled_start.naive_local()),
+                ));
+            }
+        }
+    }
+
+    diagnostics.into_iter()
+}
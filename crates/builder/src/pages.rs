@@ -1,12 +1,66 @@
 pub(crate) mod add;
 mod index;
+mod mobs;
+mod people;
+mod tags;
 
-use ssg_child::FileSpec;
+use maud::Markup;
+use ssg_child::{
+    sources::{BytesSource, ExpectedFiles},
+    FileSpec, Fingerprints,
+};
 
-use crate::mob::Mob;
+use crate::{mob::Mob, relative_path::RelativePathBuf, url::Url};
 
-pub(crate) fn all(mobs: Vec<Mob>) -> impl Iterator<Item = FileSpec> {
-    [index::page(&mobs), add::page()]
-        .into_iter()
-        .chain(mobs.into_iter().map(Mob::page))
+/// A rendered page, which the stylesheet is generated from before the page is specified as a file
+pub(crate) struct PageFile {
+    path: RelativePathBuf,
+    markup: Markup,
+    expected_files: ExpectedFiles,
+}
+
+impl PageFile {
+    pub(crate) fn new(
+        path: RelativePathBuf,
+        markup: Markup,
+        expected_files: ExpectedFiles,
+    ) -> Self {
+        Self {
+            path,
+            markup,
+            expected_files,
+        }
+    }
+
+    pub(crate) fn html(&self) -> &str {
+        &self.markup.0
+    }
+}
+
+impl From<PageFile> for FileSpec {
+    fn from(page_file: PageFile) -> Self {
+        let bytes = page_file.markup.0.into_bytes();
+
+        FileSpec::new(
+            page_file.path,
+            BytesSource::new(bytes, Some(page_file.expected_files)),
+        )
+        .with_label("page")
+    }
+}
+
+pub(crate) fn all<'a>(
+    mobs: Vec<Mob>,
+    base_url: &'a Url,
+    fingerprints: &'a Fingerprints,
+) -> impl Iterator<Item = PageFile> + 'a {
+    [
+        index::page(&mobs, base_url, fingerprints),
+        add::page(base_url, fingerprints),
+        mobs::page(&mobs, base_url, fingerprints),
+    ]
+    .into_iter()
+    .chain(tags::all(&mobs, base_url, fingerprints))
+    .chain(people::all(&mobs, base_url, fingerprints))
+    .chain(mobs.into_iter().map(|mob| mob.page(base_url, fingerprints)))
 }
@@ -4,18 +4,18 @@ use indexmap::IndexMap;
 use maud::Render;
 use once_cell::sync::Lazy;
 use schema::{DeriveInput, Schema};
-use ssg_child::{
-    sources::{BytesSource, ExpectedFiles},
-    FileSpec,
-};
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
 
 use crate::{
     components::{
         self,
         schema::type_::{ident::TypeIdent, Type},
     },
-    mob,
+    expected_files::ExpectedFilesExt,
+    json_schema, mob,
+    pages::PageFile,
     relative_path::RelativePathBuf,
+    url::Url,
 };
 
 #[derive(Clone)]
@@ -42,10 +42,11 @@ pub(crate) static INTERNAL_TYPES_DERIVE_INPUTS: Lazy<IndexMap<TypeIdent, DeriveI
     Lazy::new(|| {
         [
             mob::MobFile::schema(),
-            mob::Participant::schema(),
+            mob::YamlParticipant::schema(),
             mob::Person::schema(),
             mob::YamlRecurringSession::schema(),
             mob::Link::schema(),
+            mob::link::Website::schema(),
             mob::Status::schema(),
         ]
         .map(|derive_input| (derive_input.ident.to_string().into(), derive_input.into()))
@@ -53,7 +54,7 @@ pub(crate) static INTERNAL_TYPES_DERIVE_INPUTS: Lazy<IndexMap<TypeIdent, DeriveI
         .collect()
     });
 
-pub fn page() -> FileSpec {
+pub fn page(base_url: &Url, fingerprints: &Fingerprints) -> PageFile {
     let current_path = RelativePathBuf::from("/add.html");
 
     let internal_types = INTERNAL_TYPES_DERIVE_INPUTS
@@ -61,11 +62,16 @@ pub fn page() -> FileSpec {
         .map(|derive_input| Type::try_from(derive_input.deref().clone()).unwrap())
         .collect::<Vec<Type>>();
 
-    let mut expected_files = ExpectedFiles::default();
-    let base = components::PageBase::new(&mut expected_files, current_path.clone());
-    let add_page = components::add_page::AddPage::new(internal_types, base);
+    let mut expected_files = ExpectedFiles::new(fingerprints);
+    let base = components::PageBase::new(&mut expected_files, current_path.clone(), base_url);
+    let json_schema_path = expected_files.insert_(json_schema::PATH);
 
-    let bytes = add_page.render().0.into_bytes();
+    let add_page = components::add_page::AddPage::new(
+        internal_types,
+        base,
+        json_schema_path,
+        json_schema::yaml_custom_tags(),
+    );
 
-    FileSpec::new(current_path, BytesSource::new(bytes, Some(expected_files)))
+    PageFile::new(current_path, add_page.render(), expected_files)
 }
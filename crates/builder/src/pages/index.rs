@@ -1,36 +1,43 @@
+use chrono::Utc;
 use maud::Render;
 
-use ssg_child::sources::BytesSource;
-use ssg_child::sources::ExpectedFiles;
-use ssg_child::FileSpec;
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
 
 use crate::components::home_page::event_content_template;
 use crate::expected_files::ExpectedFilesExt;
 use crate::mob::Mob;
+use crate::pages::PageFile;
 use crate::relative_path::RelativePathBuf;
+use crate::url::Url;
 use crate::{components, mob};
 
-pub fn page(mobs: &[Mob]) -> FileSpec {
+pub fn page(mobs: &[Mob], base_url: &Url, fingerprints: &Fingerprints) -> PageFile {
     let path = RelativePathBuf::from("/index.html");
-    let mut expected_files = ExpectedFiles::default();
+    let mut expected_files = ExpectedFiles::new(fingerprints);
 
     let participants = mob::get_all_participants(mobs);
 
     let events = mobs
         .iter()
-        .filter(|mob| {
-            !matches!(
-                mob.status(),
-                mob::Status::Terminated(_) | mob::Status::Renamed(_)
-            )
-        })
+        .filter(|mob| mob.is_active())
         .map(|mob| mob.events(&mut expected_files, event_content_template))
         .collect::<Vec<_>>()
         .into_iter()
         .flatten()
         .collect();
 
-    let base = components::PageBase::new(&mut expected_files, path.clone());
+    let as_of = Utc::now();
+
+    let upcoming_sessions = mobs
+        .iter()
+        .filter(|mob| mob.is_active())
+        .flat_map(|mob| {
+            let mob_path = expected_files.insert_(format!("/mobs/{}.html", mob.id()));
+            mob.upcoming_sessions(as_of, Some(&mob_path))
+        })
+        .collect();
+
+    let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
 
     let add_page_path = expected_files.insert_("/add.html");
 
@@ -40,12 +47,13 @@ pub fn page(mobs: &[Mob]) -> FileSpec {
         events,
         base,
         add_page_path,
+        expected_files.insert_("/mobs.html"),
         expected_files.insert_("/fullcalendar.js"),
         expected_files.insert_("/rrule.js"),
         expected_files.insert_("/fullcalendar_rrule.js"),
+        expected_files.insert_("/calendar.ics"),
+        components::UpcomingSessions::new(as_of, upcoming_sessions),
     );
 
-    let bytes = home_page.render().0.into_bytes();
-
-    FileSpec::new(path, BytesSource::new(bytes, Some(expected_files)))
+    PageFile::new(path, home_page.render(), expected_files)
 }
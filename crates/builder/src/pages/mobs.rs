@@ -0,0 +1,56 @@
+use chrono::Utc;
+use maud::Render;
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
+use strum::VariantNames;
+
+use crate::{
+    components::{self, mobs_page::MobsPageEntry},
+    expected_files::ExpectedFilesExt,
+    mob::{Mob, Status},
+    pages::PageFile,
+    relative_path::RelativePathBuf,
+    url::Url,
+};
+
+pub(super) fn page(mobs: &[Mob], base_url: &Url, fingerprints: &Fingerprints) -> PageFile {
+    let path = RelativePathBuf::from("/mobs.html");
+    let mut expected_files = ExpectedFiles::new(fingerprints);
+    let as_of = Utc::now();
+
+    let mut entries = |filter: &dyn Fn(&Status) -> bool| {
+        let mut entries = mobs
+            .iter()
+            .filter(|mob| filter(mob.status()))
+            .map(|mob| {
+                let mob_page_path = expected_files.insert_(format!("/mobs/{}.html", mob.id()));
+                let next_session = mob.is_active().then(|| mob.next_session(as_of)).flatten();
+
+                MobsPageEntry::new(mob.clone(), mob_page_path, next_session)
+            })
+            .collect::<Vec<_>>();
+
+        entries.sort_by_key(|entry| entry.mob().title().as_str().to_lowercase());
+        entries
+    };
+
+    let groups = Status::VARIANTS
+        .iter()
+        .filter(|&&variant| !matches!(variant, "Renamed" | "Terminated"))
+        .map(|&variant| {
+            (
+                variant,
+                entries(&|status: &Status| status.as_ref() == variant),
+            )
+        })
+        .filter(|(_, entries)| !entries.is_empty())
+        .map(|(variant, entries)| (variant.to_owned(), Status::description(variant), entries))
+        .collect();
+
+    let archive = entries(&|status: &Status| matches!(status, Status::Terminated(_)));
+
+    let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
+
+    let page = components::mobs_page::MobsPage::new(base, Status::legend(), groups, archive, as_of);
+
+    PageFile::new(path, page.render(), expected_files)
+}
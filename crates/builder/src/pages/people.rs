@@ -0,0 +1,67 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use maud::Render;
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
+
+use crate::{
+    components,
+    expected_files::ExpectedFilesExt,
+    mob::{Mob, Participant, Person, Status},
+    pages::PageFile,
+    relative_path::RelativePathBuf,
+    url::Url,
+};
+
+pub(super) fn all(mobs: &[Mob], base_url: &Url, fingerprints: &Fingerprints) -> Vec<PageFile> {
+    // the same person may be listed with slightly different details in different mobs
+    let mut people_by_slug = BTreeMap::<String, (BTreeSet<&Person>, Vec<&Mob>)>::new();
+
+    for mob in mobs {
+        if matches!(mob.status(), Status::Renamed(_)) {
+            continue;
+        }
+
+        for participant in mob.participants() {
+            let Participant::Public(person) = participant else {
+                continue;
+            };
+
+            let (people, mobs) = people_by_slug.entry(person.name().slug()).or_default();
+            people.insert(person);
+            mobs.push(mob);
+        }
+    }
+
+    people_by_slug
+        .into_iter()
+        .filter_map(|(slug, (people, mobs))| {
+            let person = people.into_iter().next()?;
+            Some(person_page(&slug, person, &mobs, base_url, fingerprints))
+        })
+        .collect()
+}
+
+fn person_page(
+    slug: &str,
+    person: &Person,
+    mobs: &[&Mob],
+    base_url: &Url,
+    fingerprints: &Fingerprints,
+) -> PageFile {
+    let path = RelativePathBuf::from(format!("/people/{slug}.html"));
+    let mut expected_files = ExpectedFiles::new(fingerprints);
+    let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
+
+    let mut mobs = mobs
+        .iter()
+        .map(|&mob| {
+            let mob_page_path = expected_files.insert_(format!("/mobs/{}.html", mob.id()));
+            (mob.clone(), mob_page_path)
+        })
+        .collect::<Vec<_>>();
+
+    mobs.sort_by_key(|(mob, _)| mob.title().as_str().to_lowercase());
+
+    let page = components::person_page::PersonPage::new(base, person.clone(), mobs);
+    PageFile::new(path, page.render(), expected_files)
+}
@@ -0,0 +1,74 @@
+use std::collections::BTreeMap;
+
+use maud::Render;
+use ssg_child::{sources::ExpectedFiles, Fingerprints};
+
+use crate::{
+    components,
+    expected_files::ExpectedFilesExt,
+    mob::{Mob, Status, Tag},
+    pages::PageFile,
+    relative_path::RelativePathBuf,
+    url::Url,
+};
+
+const INDEX_PATH: &str = "/tags.html";
+
+pub(super) fn all(mobs: &[Mob], base_url: &Url, fingerprints: &Fingerprints) -> Vec<PageFile> {
+    let mut mobs_by_tag = BTreeMap::<&Tag, Vec<&Mob>>::new();
+
+    for mob in mobs {
+        if matches!(mob.status(), Status::Renamed(_)) {
+            continue;
+        }
+
+        for tag in mob.tags() {
+            mobs_by_tag.entry(tag).or_default().push(mob);
+        }
+    }
+
+    mobs_by_tag
+        .iter()
+        .map(|(tag, mobs)| tag_page(tag, mobs, base_url, fingerprints))
+        .chain([index_page(&mobs_by_tag, base_url, fingerprints)])
+        .collect()
+}
+
+fn index_page(
+    mobs_by_tag: &BTreeMap<&Tag, Vec<&Mob>>,
+    base_url: &Url,
+    fingerprints: &Fingerprints,
+) -> PageFile {
+    let path = RelativePathBuf::from(INDEX_PATH);
+    let mut expected_files = ExpectedFiles::new(fingerprints);
+    let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
+
+    let tags = mobs_by_tag
+        .iter()
+        .map(|(&tag, mobs)| {
+            let tag_page_path = expected_files.insert_(format!("/tags/{tag}.html"));
+            (tag.clone(), mobs.len(), tag_page_path)
+        })
+        .collect();
+
+    let page = components::tags_page::TagsPage::new(base, tags);
+    PageFile::new(path, page.render(), expected_files)
+}
+
+fn tag_page(tag: &Tag, mobs: &[&Mob], base_url: &Url, fingerprints: &Fingerprints) -> PageFile {
+    let path = RelativePathBuf::from(format!("/tags/{tag}.html"));
+    let mut expected_files = ExpectedFiles::new(fingerprints);
+    let base = components::PageBase::new(&mut expected_files, path.clone(), base_url);
+
+    let mobs = mobs
+        .iter()
+        .map(|&mob| {
+            let mob_page_path = expected_files.insert_(format!("/mobs/{}.html", mob.id()));
+            (mob.clone(), mob_page_path)
+        })
+        .collect();
+
+    let tags_page_path = expected_files.insert_(INDEX_PATH);
+    let page = components::tag_page::TagPage::new(base, tag.clone(), mobs, tags_page_path);
+    PageFile::new(path, page.render(), expected_files)
+}
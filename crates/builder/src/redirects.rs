@@ -0,0 +1,94 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::Write,
+};
+
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::mob::{Mob, Status};
+
+/// For hosts that support it, such as Netlify
+const NETLIFY_PATH: &str = "/_redirects";
+/// For hosts that read redirects from some other configuration
+const JSON_PATH: &str = "/redirects.json";
+
+/// Permanent redirects from the pages of renamed mobs to the pages of the mobs they were renamed to
+pub(crate) fn file_specs(mobs: &[Mob]) -> [FileSpec; 2] {
+    let redirects = redirects(mobs);
+    let netlify = netlify(&redirects);
+    let json = serde_json::to_vec_pretty(&redirects).unwrap();
+
+    [
+        FileSpec::new(NETLIFY_PATH, BytesSource::new(netlify.into_bytes(), None)),
+        FileSpec::new(JSON_PATH, BytesSource::new(json, None)),
+    ]
+    .map(|file_spec| file_spec.with_label("redirects"))
+}
+
+/// In Netlify's `_redirects` format
+fn netlify(redirects: &BTreeMap<String, String>) -> String {
+    let mut netlify = String::new();
+
+    // forced, because a page that redirects is still generated at each `from` path,
+    // and Netlify would serve it instead of an unforced redirect
+    for (from, to) in redirects {
+        writeln!(netlify, "{from} {to} 301!").unwrap();
+    }
+
+    netlify
+}
+
+/// Each renamed mob's page path with the page path of its current name
+fn redirects(mobs: &[Mob]) -> BTreeMap<String, String> {
+    let renames = mobs
+        .iter()
+        .filter_map(|mob| match mob.status() {
+            Status::Renamed(renamed_id) => Some((mob.id().as_str(), renamed_id.as_str())),
+            _ => None,
+        })
+        .collect::<BTreeMap<_, _>>();
+
+    renames
+        .iter()
+        .map(|(&from, &to)| {
+            // a mob may have been renamed more than once, so the redirect is straight to the latest name,
+            // unless the renames form a cycle
+            let mut visited = BTreeSet::from([from]);
+            let mut current = to;
+
+            while let Some(&next) = renames.get(current) {
+                if !visited.insert(current) {
+                    current = to;
+                    break;
+                }
+                current = next;
+            }
+
+            (page_path(from), page_path(current))
+        })
+        .collect()
+}
+
+fn page_path(id: &str) -> String {
+    format!("/mobs/{id}.html")
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeMap;
+
+    use super::netlify;
+
+    #[test]
+    fn netlify_redirects_are_forced() {
+        let redirects = BTreeMap::from([
+            ("/mobs/old.html".to_owned(), "/mobs/new.html".to_owned()),
+            ("/mobs/older.html".to_owned(), "/mobs/new.html".to_owned()),
+        ]);
+
+        assert_eq!(
+            netlify(&redirects),
+            "/mobs/old.html /mobs/new.html 301!\n/mobs/older.html /mobs/new.html 301!\n"
+        );
+    }
+}
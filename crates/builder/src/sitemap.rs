@@ -0,0 +1,91 @@
+use std::{collections::BTreeSet, fmt::Write};
+
+use camino::Utf8Path;
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{url::Url, xml::escape};
+
+const SITEMAP_PATH: &str = "/sitemap.xml";
+
+/// A sitemap of the HTML pages among `paths`, except for the `excluded`, and a robots.txt that refers to it
+pub(crate) fn file_specs<'a>(
+    base_url: &Url,
+    paths: impl IntoIterator<Item = &'a str>,
+    excluded: &BTreeSet<String>,
+) -> [FileSpec; 2] {
+    let urls = urls(base_url, paths, excluded);
+
+    let mut sitemap = String::new();
+    writeln!(sitemap, r#"<?xml version="1.0" encoding="utf-8"?>"#).unwrap();
+    writeln!(
+        sitemap,
+        r#"<urlset xmlns="http://www.sitemaps.org/schemas/sitemap/0.9">"#
+    )
+    .unwrap();
+
+    for url in urls {
+        writeln!(sitemap, "  <url><loc>{}</loc></url>", escape(url.as_str())).unwrap();
+    }
+
+    writeln!(sitemap, "</urlset>").unwrap();
+
+    let robots = format!(
+        "User-agent: *\nAllow: /\nSitemap: {}\n",
+        base_url.join(SITEMAP_PATH).unwrap().as_str()
+    );
+
+    [
+        FileSpec::new(SITEMAP_PATH, BytesSource::new(sitemap.into_bytes(), None))
+            .with_label("sitemap"),
+        FileSpec::new("/robots.txt", BytesSource::new(robots.into_bytes(), None))
+            .with_label("robots"),
+    ]
+}
+
+fn urls<'a>(
+    base_url: &Url,
+    paths: impl IntoIterator<Item = &'a str>,
+    excluded: &BTreeSet<String>,
+) -> BTreeSet<Url> {
+    paths
+        .into_iter()
+        .filter(|path| Utf8Path::new(path).extension() == Some("html") && !excluded.contains(*path))
+        .map(|path| path.strip_suffix("index.html").unwrap_or(path))
+        .map(|path| base_url.join(path).unwrap())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::BTreeSet;
+
+    use crate::url::Url;
+
+    use super::urls;
+
+    #[test]
+    fn subpath_base_url() {
+        let base_url = Url::parse("https://example.org/mobus").unwrap();
+        let excluded = BTreeSet::from(["/404.html".to_owned()]);
+
+        let paths = [
+            "/index.html",
+            "/mobs/pangolin.html",
+            "/404.html",
+            "/feed.atom",
+        ];
+
+        let urls = urls(&base_url, paths, &excluded)
+            .iter()
+            .map(|url| url.as_str().to_owned())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            urls,
+            [
+                "https://example.org/mobus/",
+                "https://example.org/mobus/mobs/pangolin.html",
+            ]
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use csscolorparser::Color;
+use maud::html;
+use resvg::{tiny_skia, usvg};
+use ssg_child::{sources::BytesSource, FileSpec};
+
+use crate::{
+    constants::{DESCRIPTION, NAME},
+    fonts,
+    mob::{Mob, Status},
+};
+
+pub(crate) const SITE_PATH: &str = "/preview.png";
+
+const WIDTH: u32 = 1200;
+const HEIGHT: u32 = 630;
+const MARGIN: u32 = 80;
+
+pub(crate) fn mob_path(mob: &Mob) -> String {
+    format!("/mobs/{}.png", mob.id())
+}
+
+pub(crate) fn all(mobs: &[Mob]) -> Vec<FileSpec> {
+    // the page's background and text colors
+    let site = Preview {
+        title: NAME,
+        subtitle: Some(DESCRIPTION),
+        background_color: &Color::from_html("#111827").unwrap(),
+        text_color: &Color::from_html("#f3f4f6").unwrap(),
+    };
+
+    mobs.iter()
+        .filter(|mob| !matches!(mob.status(), Status::Renamed(_)))
+        .map(|mob| {
+            let subtitle = mob.subtitle().as_ref().map(ToString::to_string);
+
+            let preview = Preview {
+                title: &mob.title().to_string(),
+                subtitle: subtitle.as_deref(),
+                background_color: mob.background_color(),
+                text_color: mob.text_color(),
+            };
+
+            FileSpec::new(mob_path(mob), BytesSource::new(preview.png(), None))
+                .with_label("social preview")
+        })
+        .chain(
+            [FileSpec::new(SITE_PATH, BytesSource::new(site.png(), None))
+                .with_label("social preview")],
+        )
+        .collect()
+}
+
+struct Preview<'a> {
+    title: &'a str,
+    subtitle: Option<&'a str>,
+    background_color: &'a Color,
+    text_color: &'a Color,
+}
+
+impl Preview<'_> {
+    fn svg(&self) -> String {
+        const TITLE_SIZE: u32 = 80;
+        const SUBTITLE_SIZE: u32 = 40;
+
+        // the baseline of each line, with some leading
+        let title_lines = (1..)
+            .zip(wrap(self.title, 22))
+            .map(|(index, line)| (MARGIN + TITLE_SIZE * 5 / 4 * index, line))
+            .collect::<Vec<_>>();
+
+        let subtitle_top =
+            MARGIN + TITLE_SIZE * 5 / 4 * u32::try_from(title_lines.len()).unwrap() + SUBTITLE_SIZE;
+
+        let subtitle_lines = (1..)
+            .zip(
+                self.subtitle
+                    .map(|subtitle| wrap(subtitle, 44))
+                    .unwrap_or_default(),
+            )
+            .map(|(index, line)| (subtitle_top + SUBTITLE_SIZE * 5 / 4 * index, line))
+            .collect::<Vec<_>>();
+
+        let name_y = HEIGHT - MARGIN;
+
+        html! {
+            svg
+                xmlns="http://www.w3.org/2000/svg"
+                width=(WIDTH)
+                height=(HEIGHT)
+                font-family=(fonts::VOLLKORN.family())
+                fill=(self.text_color.to_hex_string())
+            {
+                rect width=(WIDTH) height=(HEIGHT) fill=(self.background_color.to_hex_string()) {}
+
+                @for (y, line) in &title_lines {
+                    text x=(MARGIN) y=(y) font-size=(TITLE_SIZE) { (line) }
+                }
+
+                @for (y, line) in &subtitle_lines {
+                    text x=(MARGIN) y=(y) font-size=(SUBTITLE_SIZE) { (line) }
+                }
+
+                text x=(MARGIN) y=(name_y) font-size=(SUBTITLE_SIZE) letter-spacing="4" { (NAME.to_uppercase()) }
+            }
+        }
+        .into_string()
+    }
+
+    fn png(&self) -> Vec<u8> {
+        let mut options = usvg::Options::default();
+        options
+            .fontdb_mut()
+            .load_font_data(fonts::VOLLKORN.bytes().to_vec());
+
+        let tree = usvg::Tree::from_str(&self.svg(), &options).unwrap();
+        let mut pixmap = tiny_skia::Pixmap::new(WIDTH, HEIGHT).unwrap();
+        resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+
+        pixmap.encode_png().unwrap()
+    }
+}
+
+/// SVG text does not wrap, so lines are broken between words at a number of characters
+fn wrap(text: &str, max_line_chars: usize) -> Vec<String> {
+    let mut lines: Vec<String> = vec![];
+
+    for word in text.split_whitespace() {
+        match lines.last_mut() {
+            Some(line) if line.chars().count() + 1 + word.chars().count() <= max_line_chars => {
+                line.push(' ');
+                line.push_str(word);
+            }
+            _ => lines.push(word.to_owned()),
+        }
+    }
+
+    lines
+}
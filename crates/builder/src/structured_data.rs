@@ -0,0 +1,226 @@
+use chrono::{DateTime, Utc, Weekday};
+use maud::{html, Markup, PreEscaped};
+use rrule::{Frequency, NWeekday, RRule, Unvalidated};
+use serde_json::{json, Value};
+
+use crate::{
+    components::UpcomingSessions,
+    constants::NAME,
+    mob::{Mob, Participant, RecurringSession, Status},
+    social_preview,
+    url::Url,
+};
+
+const SCHEMA_ORG: &str = "https://schema.org";
+
+/// The mob as a schema.org `EventSeries` for search engines,
+/// with its sessions in the weeks following the build as `Event`s
+pub(crate) fn event_series(mob: &Mob, as_of: DateTime<Utc>, base_url: &Url) -> Value {
+    let url = base_url.join(&format!("/mobs/{}.html", mob.id())).unwrap();
+    let image = base_url.join(&social_preview::mob_path(mob)).unwrap();
+
+    let event_status = if matches!(mob.status(), Status::Terminated(_)) {
+        format!("{SCHEMA_ORG}/EventCancelled")
+    } else {
+        format!("{SCHEMA_ORG}/EventScheduled")
+    };
+
+    let attendance_mode = format!("{SCHEMA_ORG}/OnlineEventAttendanceMode");
+    let location = json!({ "@type": "VirtualLocation", "url": url });
+
+    // the website publishes the mob, and the participants attend it
+    let publisher =
+        json!({ "@type": "Organization", "name": NAME, "url": base_url.join("/").unwrap() });
+
+    let attendees = mob
+        .participants()
+        .iter()
+        .filter_map(|participant| match participant {
+            Participant::Hidden => None,
+            Participant::Public(person) => Some(json!({
+                "@type": "Person",
+                "name": person.name().to_string(),
+                "url": person.social_url(),
+            })),
+        })
+        .collect::<Vec<_>>();
+
+    // a terminated mob's sessions do not take place;
+    // the sessions share the series' location and organizer
+    let sub_events = if mob.is_active() {
+        mob.schedule()
+            .iter()
+            .flat_map(|recurring_session| {
+                recurring_session
+                    .occurrences(as_of, UpcomingSessions::until(as_of))
+                    .into_iter()
+                    .map(|start| {
+                        json!({
+                            "@type": "Event",
+                            "name": mob.title().as_str(),
+                            "url": url,
+                            "startDate": start.to_rfc3339(),
+                            "endDate": (start + recurring_session.duration()).to_rfc3339(),
+                            "eventStatus": event_status,
+                            "eventAttendanceMode": attendance_mode,
+                        })
+                    })
+            })
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+
+    let start_date = mob
+        .schedule()
+        .iter()
+        .map(RecurringSession::start)
+        .min()
+        .map(|start| start.to_rfc3339());
+
+    json!({
+        "@context": SCHEMA_ORG,
+        "@type": "EventSeries",
+        "name": mob.title().as_str(),
+        "description": mob.subtitle().as_ref().map(ToString::to_string),
+        "url": url,
+        "image": image,
+        "startDate": start_date,
+        "eventStatus": event_status,
+        "eventAttendanceMode": attendance_mode,
+        "location": location,
+        "organizer": publisher,
+        "attendee": attendees,
+        "isAccessibleForFree": true,
+        "publisher": publisher,
+        "eventSchedule": mob.schedule().iter().map(schedule).collect::<Vec<_>>(),
+        "subEvent": sub_events,
+    })
+}
+
+/// For embedding in the page's head
+pub(crate) fn script(value: &Value) -> Markup {
+    // `</script>` in a string would end the script element early
+    let json = value.to_string().replace("</", r"<\/");
+
+    html! {
+        script type="application/ld+json" { (PreEscaped(json)) }
+    }
+}
+
+fn schedule(recurring_session: &RecurringSession) -> Value {
+    let start = recurring_session.start();
+    let end = start + recurring_session.duration();
+
+    let mut schedule = json!({
+        "@type": "Schedule",
+        "startDate": start.date_naive().to_string(),
+        "startTime": start.format("%H:%M").to_string(),
+        "endTime": end.format("%H:%M").to_string(),
+        "duration": format!("PT{}M", recurring_session.duration().num_minutes()),
+        "scheduleTimezone": start.timezone().name(),
+        "exceptDate": recurring_session
+            .cancelled_starts()
+            .iter()
+            .map(|start| start.date_naive().to_string())
+            .collect::<Vec<_>>(),
+    });
+
+    // a single session is described by its start date alone
+    if let Some(frequency) = recurring_session.frequency() {
+        let rrule: RRule<Unvalidated> = frequency.clone().into();
+        let Value::Object(fields) = &mut schedule else {
+            unreachable!()
+        };
+
+        fields.insert(
+            "repeatFrequency".to_owned(),
+            repeat_frequency(&rrule).into(),
+        );
+
+        if !rrule.get_by_weekday().is_empty() {
+            let by_day = rrule.get_by_weekday().iter().copied().map(by_day).collect();
+            fields.insert("byDay".to_owned(), Value::Array(by_day));
+        }
+
+        if !rrule.get_by_month_day().is_empty() {
+            fields.insert("byMonthDay".to_owned(), rrule.get_by_month_day().into());
+        }
+
+        if !rrule.get_by_month().is_empty() {
+            fields.insert("byMonth".to_owned(), rrule.get_by_month().into());
+        }
+
+        if let Some(count) = rrule.get_count() {
+            fields.insert("repeatCount".to_owned(), count.into());
+        }
+
+        if let Some(until) = rrule.get_until() {
+            fields.insert("endDate".to_owned(), until.date_naive().to_string().into());
+        }
+    }
+
+    schedule
+}
+
+/// As an ISO 8601 duration
+fn repeat_frequency(rrule: &RRule<Unvalidated>) -> String {
+    let interval = rrule.get_interval();
+
+    match rrule.get_freq() {
+        Frequency::Yearly => format!("P{interval}Y"),
+        Frequency::Monthly => format!("P{interval}M"),
+        Frequency::Weekly => format!("P{interval}W"),
+        Frequency::Daily => format!("P{interval}D"),
+        Frequency::Hourly => format!("PT{interval}H"),
+        Frequency::Minutely => format!("PT{interval}M"),
+        Frequency::Secondly => format!("PT{interval}S"),
+    }
+}
+
+/// A `DayOfWeek` for every such weekday, or iCalendar's notation, such as `2MO`, for the nth
+fn by_day(weekday: NWeekday) -> Value {
+    match weekday {
+        NWeekday::Every(weekday) => {
+            let name = match weekday {
+                Weekday::Mon => "Monday",
+                Weekday::Tue => "Tuesday",
+                Weekday::Wed => "Wednesday",
+                Weekday::Thu => "Thursday",
+                Weekday::Fri => "Friday",
+                Weekday::Sat => "Saturday",
+                Weekday::Sun => "Sunday",
+            };
+
+            format!("{SCHEMA_ORG}/{name}").into()
+        }
+        NWeekday::Nth(n, weekday) => {
+            let weekday = weekday.to_string().to_uppercase();
+            format!("{n}{}", &weekday[..2]).into()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rrule::{RRule, Unvalidated};
+    use serde_json::json;
+
+    use super::{by_day, repeat_frequency};
+
+    #[test]
+    fn schedule_recurrence() {
+        let rrule: RRule<Unvalidated> = "FREQ=MONTHLY;INTERVAL=2;BYDAY=TU,-1FR".parse().unwrap();
+
+        assert_eq!(repeat_frequency(&rrule), "P2M");
+
+        let by_day = rrule
+            .get_by_weekday()
+            .iter()
+            .copied()
+            .map(by_day)
+            .collect::<Vec<_>>();
+
+        assert_eq!(by_day, [json!("https://schema.org/Tuesday"), json!("-1FR")]);
+    }
+}
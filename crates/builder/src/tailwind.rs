@@ -1,31 +1,42 @@
 use std::io::{stdout, Write};
 
-use camino::{Utf8Path, Utf8PathBuf};
-use tempfile::NamedTempFile;
-use tokio::process::Command;
+use camino::Utf8Path;
+use ssg_child::{sources::BytesSource, FileSpec, Fingerprints};
+use tokio::{fs, process::Command};
 
-pub(crate) async fn execute(output_dir: &Utf8Path) {
-    let input_contents = include_bytes!(env!("TAILWINDCSS_INPUT"));
-    let mut input_file = NamedTempFile::new().unwrap();
-    input_file.write_all(input_contents).unwrap();
+use crate::pages::PageFile;
+
+pub(crate) const PATH: &str = "/index.css";
+
+/// The stylesheet, with the rules for the classes that the pages use, at a fingerprinted path
+pub(crate) async fn file_spec(pages: &[PageFile], fingerprints: &mut Fingerprints) -> FileSpec {
+    // tailwind scans files for classes, so the pages are written to a directory of their own
+    let temp_dir = tempfile::tempdir().unwrap();
+    let directory = Utf8Path::from_path(temp_dir.path()).unwrap();
+
+    for (index, page) in pages.iter().enumerate() {
+        fs::write(directory.join(format!("{index}.html")), page.html())
+            .await
+            .unwrap();
+    }
+
+    let input_path = directory.join("input.css");
+    fs::write(&input_path, include_bytes!(env!("TAILWINDCSS_INPUT")))
+        .await
+        .unwrap();
+
+    let output_path = directory.join("index.css");
 
     let output = Command::new(env!("TAILWINDCSS"))
         .args([
             "--config",
             env!("TAILWINDCSS_CONFIG"),
             "--input",
-            input_file.path().to_str().unwrap(),
+            input_path.as_str(),
             "--output",
-            [".".as_ref(), output_dir, "index.css".as_ref()]
-                .iter()
-                .collect::<Utf8PathBuf>()
-                .as_ref(),
+            output_path.as_str(),
             "--content",
-            // TODO explicit list instead of pattern
-            [".".as_ref(), output_dir, "**".as_ref(), "*.html".as_ref()]
-                .iter()
-                .collect::<Utf8PathBuf>()
-                .as_ref(),
+            directory.join("*.html").as_str(),
         ])
         .output()
         .await
@@ -34,4 +45,12 @@ pub(crate) async fn execute(output_dir: &Utf8Path) {
     stdout().write_all(&output.stderr).unwrap();
 
     assert!(output.status.success());
+
+    let bytes = fs::read(output_path).await.unwrap();
+
+    FileSpec::new(
+        fingerprints.insert(PATH, &bytes),
+        BytesSource::new(bytes, None),
+    )
+    .with_label("stylesheet")
 }
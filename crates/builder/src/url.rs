@@ -14,6 +14,22 @@ impl Url {
         self.0.set_path(path);
     }
 
+    /// Resolves a path of the website, such as `/mobs.html`, beneath this URL's path,
+    /// so that a website served from a subpath, such as `https://example.org/mobus/`, stays within it
+    pub(crate) fn join(&self, path: &str) -> Result<Self, url::ParseError> {
+        let mut base = self.0.clone();
+
+        if !base.path().ends_with('/') {
+            base.set_path(&format!("{}/", base.path()));
+        }
+
+        base.join(path.trim_start_matches('/')).map(Self)
+    }
+
+    pub(crate) fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
     pub(crate) fn path_segments_mut(&mut self) -> Result<url::PathSegmentsMut, ()> {
         self.0.path_segments_mut()
     }
@@ -8,6 +8,9 @@ use ssg_parent::Parent;
 struct Cli {
     mobs_path: Utf8PathBuf,
     output_dir: Utf8PathBuf,
+    /// people file, whose entries participants may refer to by key
+    #[arg(long, default_value = "people.yaml")]
+    people: Utf8PathBuf,
     /// open website in a browser
     #[arg(short, long)]
     open: bool,
@@ -28,9 +31,15 @@ async fn main() {
             "--package",
             "builder",
             "--",
+            "build",
             cli.mobs_path.as_str(),
             cli.output_dir.as_str(),
-        ],
+            "--people",
+            cli.people.as_str(),
+        ]
+        .into_iter()
+        .map(ToOwned::to_owned)
+        .collect::<Vec<String>>(),
     );
 
     let error = parent
@@ -1,20 +1,25 @@
+use std::{io, path::Path};
+
 use camino::Utf8PathBuf;
 use futures::{future::BoxFuture, FutureExt};
 use getset::Getters;
 use relative_path::RelativePathBuf;
-use tokio::{fs, io::AsyncWriteExt};
+use sha2::{Digest, Sha256};
+use tokio::fs;
 
 use crate::{
     file_error::{FileError, FileErrorCause},
-    file_success::FileSuccess,
+    file_success::{FileOutcome, FileSuccess},
+    fingerprint::Fingerprints,
     sources::FileSource,
 };
 
 #[derive(Getters)]
 pub struct FileSpec {
     source: Box<dyn FileSource + Send>,
-    #[getset(get = "pub(crate)")]
+    #[getset(get = "pub")]
     path: RelativePathBuf,
+    label: Option<&'static str>,
 }
 
 impl FileSpec {
@@ -25,9 +30,29 @@ impl FileSpec {
         Self {
             source: Box::new(source),
             path: path.into(),
+            label: None,
         }
     }
 
+    /// What the file is, such as `"page"` or `"stylesheet"`, for the manifest.
+    ///
+    /// Defaults to the type of the source, such as `"bytes"`.
+    #[must_use]
+    pub fn with_label(mut self, label: &'static str) -> Self {
+        self.label = Some(label);
+        self
+    }
+
+    /// At a path with a hash of the contents in its file name, so that it may be cached indefinitely.
+    ///
+    /// The fingerprinted path is inserted into `fingerprints`, for resolving the plain path when referring to the file.
+    pub fn fingerprinted<T>(path: T, bytes: &'static [u8], fingerprints: &mut Fingerprints) -> Self
+    where
+        RelativePathBuf: From<T>,
+    {
+        Self::new::<RelativePathBuf>(fingerprints.insert(path, bytes), bytes)
+    }
+
     pub(crate) fn into_source(self) -> Box<dyn FileSource + Send> {
         self.source
     }
@@ -38,7 +63,9 @@ impl FileSpec {
     ) -> BoxFuture<'static, Result<FileSuccess, FileError>> {
         async move {
             let this_path = self.path().clone();
+            let label = self.label;
             let source = self.into_source();
+            let label = label.unwrap_or_else(|| source.source_type());
             let task = source.obtain_content();
 
             let file_path = this_path.to_path(output_dir);
@@ -53,27 +80,93 @@ impl FileSpec {
                 FileError::new(this_path.clone(), FileErrorCause::Source(error))
             })?;
 
-            let mut file_handle = fs::OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(file_path)
+            let outcome = write_if_changed(&file_path, contents.bytes())
                 .await
                 .map_err(|error| {
                     FileError::new(this_path.clone(), FileErrorCause::OutputIo(error))
                 })?;
 
-            file_handle
-                .write_all(contents.bytes())
-                .await
-                .map_err(|error| {
-                    FileError::new(this_path.clone(), FileErrorCause::OutputIo(error))
-                })?;
+            let size = contents.bytes().len();
+            let hash = format!("{:x}", Sha256::digest(contents.bytes()));
 
             let expected_files = contents.expected_files().cloned();
 
-            Ok(FileSuccess::new(this_path, expected_files))
+            Ok(FileSuccess::new(
+                this_path,
+                expected_files,
+                outcome,
+                size,
+                hash,
+                label,
+            ))
         }
         .boxed()
     }
 }
+
+/// Leaving an unchanged file untouched keeps its modification time,
+/// so that watchers and deployments see only what actually changed
+pub(crate) async fn write_if_changed(file_path: &Path, bytes: &[u8]) -> io::Result<FileOutcome> {
+    let existing = match fs::read(file_path).await {
+        Ok(existing) => Some(existing),
+        Err(error) if error.kind() == io::ErrorKind::NotFound => None,
+        Err(error) => return Err(error),
+    };
+
+    if existing.as_deref() == Some(bytes) {
+        return Ok(FileOutcome::Unchanged);
+    }
+
+    // unlike writing to a `tokio::fs::File`, this has completed once it returns
+    fs::write(file_path, bytes).await?;
+
+    Ok(FileOutcome::Written)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, SystemTime};
+
+    use crate::file_success::FileOutcome;
+
+    use super::write_if_changed;
+
+    #[tokio::test]
+    async fn unchanged_file_is_not_rewritten() {
+        let directory = tempfile::tempdir().unwrap();
+        let file_path = directory.path().join("index.html");
+        std::fs::write(&file_path, "same").unwrap();
+
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        std::fs::File::options()
+            .write(true)
+            .open(&file_path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+
+        let outcome = write_if_changed(&file_path, b"same").await.unwrap();
+
+        assert_eq!(outcome, FileOutcome::Unchanged);
+        let metadata = std::fs::metadata(&file_path).unwrap();
+        assert_eq!(metadata.modified().unwrap(), modified);
+
+        let outcome = write_if_changed(&file_path, b"changed").await.unwrap();
+
+        assert_eq!(outcome, FileOutcome::Written);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"changed");
+        let metadata = std::fs::metadata(&file_path).unwrap();
+        assert_ne!(metadata.modified().unwrap(), modified);
+    }
+
+    #[tokio::test]
+    async fn missing_file_is_written() {
+        let directory = tempfile::tempdir().unwrap();
+        let file_path = directory.path().join("index.html");
+
+        let outcome = write_if_changed(&file_path, b"new").await.unwrap();
+
+        assert_eq!(outcome, FileOutcome::Written);
+        assert_eq!(std::fs::read(&file_path).unwrap(), b"new");
+    }
+}
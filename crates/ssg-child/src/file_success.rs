@@ -2,19 +2,48 @@ use relative_path::RelativePathBuf;
 
 use crate::sources::ExpectedFiles;
 
-#[derive(Debug, Clone, getset::Getters)]
+#[derive(Debug, Clone, getset::Getters, getset::CopyGetters)]
 pub struct FileSuccess {
-    #[getset(get = "pub(crate)")]
+    #[getset(get = "pub")]
     path: RelativePathBuf,
     #[getset(get = "pub(crate)")]
     expected_files: ExpectedFiles,
+    #[getset(get_copy = "pub")]
+    outcome: FileOutcome,
+    /// In bytes
+    #[getset(get_copy = "pub")]
+    size: usize,
+    /// SHA-256 of the contents, in hexadecimal
+    #[getset(get = "pub")]
+    hash: String,
+    /// What the file is, such as `"page"`
+    #[getset(get_copy = "pub")]
+    label: &'static str,
+}
+
+/// Whether the output file was written or already had the generated contents
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileOutcome {
+    Written,
+    Unchanged,
 }
 
 impl FileSuccess {
-    pub(super) fn new(path: RelativePathBuf, expected_files: Option<ExpectedFiles>) -> Self {
+    pub(super) fn new(
+        path: RelativePathBuf,
+        expected_files: Option<ExpectedFiles>,
+        outcome: FileOutcome,
+        size: usize,
+        hash: String,
+        label: &'static str,
+    ) -> Self {
         Self {
             path,
             expected_files: expected_files.unwrap_or_default(),
+            outcome,
+            size,
+            hash,
+            label,
         }
     }
 }
@@ -76,6 +76,20 @@ impl FinalErrorBuilder {
         self
     }
 
+    /// For a failure after generation, such as of a stale file's removal, which does not count as processed
+    pub(crate) fn add_failure(mut self, file_error: &FileError) -> Self {
+        self.failed_files.insert(file_error.path().clone());
+        self
+    }
+
+    pub(crate) fn processed_files(&self) -> BTreeSet<RelativePathBuf> {
+        self.processed_files_count.clone().into_keys().collect()
+    }
+
+    pub(crate) fn is_ok(&self) -> bool {
+        self.clone().build().is_none()
+    }
+
     pub(crate) fn build(self) -> Option<FinalError> {
         let processed_files = self
             .processed_files_count
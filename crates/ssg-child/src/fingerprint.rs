@@ -0,0 +1,120 @@
+use std::collections::BTreeMap;
+
+use relative_path::{RelativePath, RelativePathBuf};
+use sha2::{Digest, Sha256};
+
+const HASH_LENGTH: usize = 8;
+
+/// From each plain path, normalized, to the fingerprinted path of the same file
+#[derive(Debug, Clone, Default)]
+pub struct Fingerprints(BTreeMap<RelativePathBuf, RelativePathBuf>);
+
+impl Fingerprints {
+    /// Returns the fingerprinted path
+    pub fn insert<P>(&mut self, path: P, bytes: &[u8]) -> RelativePathBuf
+    where
+        RelativePathBuf: From<P>,
+    {
+        let path = RelativePathBuf::from(path);
+        let fingerprinted_path = fingerprinted_path(&path, bytes);
+        self.0.insert(path.normalize(), fingerprinted_path.clone());
+        fingerprinted_path
+    }
+
+    /// The fingerprinted path, if the file at `path` is fingerprinted
+    pub fn resolve<P>(&self, path: P) -> RelativePathBuf
+    where
+        RelativePathBuf: From<P>,
+    {
+        let path = RelativePathBuf::from(path);
+
+        self.0
+            .get(&RelativePath::normalize(&path))
+            .cloned()
+            .unwrap_or(path)
+    }
+}
+
+/// Such as `/fullcalendar.3f2a1c0b.js` for `/fullcalendar.js`
+fn fingerprinted_path(path: &RelativePath, bytes: &[u8]) -> RelativePathBuf {
+    let hash = format!("{:x}", Sha256::digest(bytes));
+    let hash = &hash[..HASH_LENGTH];
+
+    let file_name = match (path.file_stem(), path.extension()) {
+        (Some(stem), Some(extension)) => format!("{stem}.{hash}.{extension}"),
+        _ => format!("{}.{hash}", path.file_name().unwrap_or_default()),
+    };
+
+    // `RelativePath::with_file_name` would drop a leading separator
+    RelativePathBuf::from(match path.as_str().rsplit_once('/') {
+        Some((directory, _)) => format!("{directory}/{file_name}"),
+        None => file_name,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use relative_path::{RelativePath, RelativePathBuf};
+
+    use crate::sources::ExpectedFiles;
+
+    use super::{fingerprinted_path, Fingerprints};
+
+    // the first characters of the SHA-256 of no bytes
+    const EMPTY_HASH: &str = "e3b0c442";
+
+    #[test]
+    fn hash_is_inserted_before_extension() {
+        assert_eq!(
+            fingerprinted_path(RelativePath::new("/fullcalendar.js"), b""),
+            format!("/fullcalendar.{EMPTY_HASH}.js"),
+        );
+
+        assert_eq!(
+            fingerprinted_path(RelativePath::new("/fonts/vollkorn.ttf"), b""),
+            format!("/fonts/vollkorn.{EMPTY_HASH}.ttf"),
+        );
+
+        assert_eq!(
+            fingerprinted_path(RelativePath::new("/LICENSE"), b""),
+            format!("/LICENSE.{EMPTY_HASH}"),
+        );
+    }
+
+    #[test]
+    fn resolve() {
+        let mut fingerprints = Fingerprints::default();
+        let fingerprinted = fingerprints.insert("/rrule.js", b"");
+
+        assert_eq!(fingerprints.resolve("/rrule.js"), fingerprinted);
+        assert_eq!(fingerprints.resolve("rrule.js"), fingerprinted);
+        assert_eq!(
+            fingerprints.resolve("/index.html"),
+            RelativePathBuf::from("/index.html"),
+        );
+    }
+
+    #[test]
+    fn stylesheet_is_expected_at_fingerprinted_path() {
+        let mut fingerprints = Fingerprints::default();
+
+        let unstyled_path = ExpectedFiles::new(&fingerprints).insert("/index.css");
+        assert_eq!(unstyled_path, RelativePathBuf::from("/index.css"));
+
+        let stylesheet_path = fingerprints.insert("/index.css", b".flex { display: flex }");
+        assert_ne!(stylesheet_path, unstyled_path);
+
+        let mut changed = Fingerprints::default();
+        assert_ne!(
+            changed.insert("/index.css", b".grid { display: grid }"),
+            stylesheet_path,
+        );
+
+        let mut expected_files = ExpectedFiles::new(&fingerprints);
+        assert_eq!(expected_files.insert("/index.css"), stylesheet_path);
+        assert_eq!(
+            expected_files.into_iter().collect::<Vec<_>>(),
+            [stylesheet_path],
+        );
+    }
+}
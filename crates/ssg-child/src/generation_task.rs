@@ -1,27 +1,38 @@
 use std::{future::IntoFuture, pin::Pin};
 
+use camino::Utf8PathBuf;
 use futures::{Future, Stream, StreamExt};
+use relative_path::RelativePathBuf;
 
 use crate::{
     file_error::FileError,
     file_success::FileSuccess,
     final_error::{FinalError, FinalErrorBuilder},
+    manifest::Manifest,
+    stale_files::StaleFileCleanup,
 };
 
 type FileResultFn = Box<dyn Fn(&Result<FileSuccess, FileError>) + 'static>;
 
 pub struct GenerationTask {
+    output_dir: Utf8PathBuf,
     file_results: Pin<Box<dyn Stream<Item = Result<FileSuccess, FileError>>>>,
     file_result_fn: Option<FileResultFn>,
+    stale_file_cleanup: Option<StaleFileCleanup>,
+    manifest_path: Option<Utf8PathBuf>,
 }
 
 impl GenerationTask {
     pub(crate) fn new(
+        output_dir: Utf8PathBuf,
         stream: impl Stream<Item = Result<FileSuccess, FileError>> + 'static,
     ) -> Self {
         Self {
+            output_dir,
             file_results: Box::pin(stream),
             file_result_fn: None,
+            stale_file_cleanup: None,
+            manifest_path: None,
         }
     }
 
@@ -31,6 +42,18 @@ impl GenerationTask {
     ) {
         self.file_result_fn = Some(Box::new(file_result_fn));
     }
+
+    /// The cleanup runs only if generation succeeded
+    pub fn set_stale_file_cleanup(&mut self, stale_file_cleanup: StaleFileCleanup) {
+        self.stale_file_cleanup = Some(stale_file_cleanup);
+    }
+
+    /// A JSON manifest of the generated files is written to this path, if generation succeeded.
+    ///
+    /// The path is not relative to the output directory, so that the manifest need not be published along with the files.
+    pub fn set_manifest_path(&mut self, manifest_path: impl Into<Utf8PathBuf>) {
+        self.manifest_path = Some(manifest_path.into());
+    }
 }
 
 impl IntoFuture for GenerationTask {
@@ -39,24 +62,61 @@ impl IntoFuture for GenerationTask {
 
     fn into_future(self) -> Self::IntoFuture {
         let Self {
+            output_dir,
             file_results,
             file_result_fn,
+            stale_file_cleanup,
+            manifest_path,
         } = self;
 
-        let future = async {
-            let final_error = file_results
-                .map(move |file_result| {
+        let future = async move {
+            let (mut final_error_builder, manifest) = file_results
+                .map(|file_result| {
                     if let Some(f) = &file_result_fn {
                         f(&file_result);
-                    };
+                    }
 
                     file_result
                 })
-                .fold(FinalErrorBuilder::default(), |builder, result| async move {
-                    builder.add(&result)
-                })
-                .await
-                .build();
+                .fold(
+                    (FinalErrorBuilder::default(), Manifest::default()),
+                    |(builder, manifest), result| async move {
+                        (builder.add(&result), manifest.add(&result))
+                    },
+                )
+                .await;
+
+            let mut failures = vec![];
+
+            if final_error_builder.is_ok() {
+                if let Some(stale_file_cleanup) = stale_file_cleanup {
+                    let mut generated_files = final_error_builder.processed_files();
+
+                    // a manifest in the output directory is not stale
+                    generated_files.extend(
+                        manifest_path
+                            .as_ref()
+                            .and_then(|path| path.strip_prefix(&output_dir).ok())
+                            .map(|path| RelativePathBuf::from(path.as_str())),
+                    );
+
+                    failures.extend(stale_file_cleanup.run(&output_dir, &generated_files).await);
+                }
+
+                if let Some(manifest_path) = &manifest_path {
+                    failures.extend(manifest.write(manifest_path).await.err());
+                }
+            }
+
+            for failure in failures {
+                final_error_builder = final_error_builder.add_failure(&failure);
+
+                if let Some(f) = &file_result_fn {
+                    f(&Err(failure));
+                }
+            }
+
+            let final_error = final_error_builder.build();
 
             if let Some(final_error) = final_error {
                 Err(final_error)
@@ -4,11 +4,15 @@ pub mod file_error;
 mod file_spec;
 pub mod file_success;
 pub mod final_error;
+mod fingerprint;
 pub mod generation_task;
+mod manifest;
 pub mod sources;
+pub mod stale_files;
 
 use camino::Utf8PathBuf;
 pub use file_spec::FileSpec;
+pub use fingerprint::Fingerprints;
 use futures::{stream, StreamExt};
 use generation_task::GenerationTask;
 
@@ -19,8 +23,11 @@ pub fn generate_static_site(
     file_specs: impl IntoIterator<Item = FileSpec> + 'static,
 ) -> GenerationTask {
     let tasks = stream::iter(file_specs)
-        .map(move |file_spec| file_spec.generate(output_dir.clone()))
+        .map({
+            let output_dir = output_dir.clone();
+            move |file_spec| file_spec.generate(output_dir.clone())
+        })
         .buffer_unordered(usize::MAX);
 
-    GenerationTask::new(tasks)
+    GenerationTask::new(output_dir, tasks)
 }
@@ -0,0 +1,125 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use camino::Utf8Path;
+use relative_path::RelativePathBuf;
+use serde::Serialize;
+use tokio::fs;
+
+use crate::{
+    file_error::{FileError, FileErrorCause},
+    file_spec::write_if_changed,
+    file_success::FileSuccess,
+};
+
+/// Each generated file, for diffing builds and inspecting which files each expects
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct Manifest(BTreeMap<String, ManifestEntry>);
+
+#[derive(Debug, Clone, Serialize)]
+struct ManifestEntry {
+    size: usize,
+    hash: String,
+    label: &'static str,
+    expected_files: BTreeSet<String>,
+}
+
+impl Manifest {
+    pub(crate) fn add(mut self, processing_result: &Result<FileSuccess, FileError>) -> Self {
+        if let Ok(success) = processing_result {
+            let entry = ManifestEntry {
+                size: success.size(),
+                hash: success.hash().clone(),
+                label: success.label(),
+                expected_files: success
+                    .expected_files()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
+            };
+
+            self.0.insert(success.path().to_string(), entry);
+        }
+
+        self
+    }
+
+    pub(crate) async fn write(&self, file_path: &Utf8Path) -> Result<(), FileError> {
+        let error = |error| {
+            FileError::new(
+                RelativePathBuf::from(file_path.as_str()),
+                FileErrorCause::OutputIo(error),
+            )
+        };
+
+        fs::create_dir_all(file_path.parent().unwrap())
+            .await
+            .map_err(error)?;
+
+        let bytes = serde_json::to_vec_pretty(self).unwrap();
+
+        write_if_changed(file_path.as_std_path(), &bytes)
+            .await
+            .map_err(error)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use camino::Utf8Path;
+    use relative_path::RelativePathBuf;
+    use serde_json::json;
+
+    use crate::{
+        file_error::{FileError, FileErrorCause},
+        file_success::{FileOutcome, FileSuccess},
+        sources::ExpectedFiles,
+    };
+
+    use super::Manifest;
+
+    #[tokio::test]
+    async fn lists_successes() {
+        let mut expected_files = ExpectedFiles::default();
+        expected_files.insert("/index.css");
+
+        let page = FileSuccess::new(
+            RelativePathBuf::from("/index.html"),
+            Some(expected_files),
+            FileOutcome::Written,
+            4,
+            "abcd".to_owned(),
+            "page",
+        );
+
+        let failure = FileError::new(
+            RelativePathBuf::from("/missing.html"),
+            FileErrorCause::OutputIo(std::io::ErrorKind::NotFound.into()),
+        );
+
+        let manifest = Manifest::default().add(&Ok(page)).add(&Err(failure));
+
+        let directory = tempfile::tempdir().unwrap();
+        let file_path = Utf8Path::from_path(directory.path())
+            .unwrap()
+            .join("build/manifest.json");
+
+        manifest.write(&file_path).await.unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(file_path).unwrap()).unwrap();
+
+        assert_eq!(
+            written,
+            json!({
+                "/index.html": {
+                    "size": 4,
+                    "hash": "abcd",
+                    "label": "page",
+                    "expected_files": ["/index.css"],
+                },
+            })
+        );
+    }
+}
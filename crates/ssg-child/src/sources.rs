@@ -10,8 +10,14 @@ use futures::future::BoxFuture;
 use getset::Getters;
 use relative_path::RelativePathBuf;
 
+use crate::Fingerprints;
+
 pub trait FileSource {
     fn obtain_content(&self) -> BoxFuture<Result<FileContents, Box<dyn std::error::Error + Send>>>;
+
+    /// Identifies the kind of source, such as in the build manifest for file specs without a label,
+    /// by a name that stays the same across releases
+    fn source_type(&self) -> &'static str;
 }
 
 #[derive(Debug, Getters)]
@@ -40,19 +46,37 @@ impl FileContents {
 }
 
 #[derive(Debug, Clone, Default, derive_more::IntoIterator)]
-pub struct ExpectedFiles(BTreeSet<RelativePathBuf>);
+pub struct ExpectedFiles {
+    #[into_iterator(owned)]
+    paths: BTreeSet<RelativePathBuf>,
+    fingerprints: Fingerprints,
+}
 
 impl ExpectedFiles {
+    /// Files that are fingerprinted in `fingerprints` are expected at their fingerprinted paths
+    #[must_use]
+    pub fn new(fingerprints: &Fingerprints) -> Self {
+        Self {
+            paths: BTreeSet::new(),
+            fingerprints: fingerprints.clone(),
+        }
+    }
+
+    /// Returns the path to refer to the file by, which is the fingerprinted path if the file is fingerprinted
     pub fn insert<P>(&mut self, path: P) -> RelativePathBuf
     where
         RelativePathBuf: From<P>,
     {
-        let path = RelativePathBuf::from(path);
-        self.0.insert(path.clone());
+        let path = self.fingerprints.resolve(path);
+        self.paths.insert(path.clone());
         path
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &RelativePathBuf> {
+        self.paths.iter()
+    }
+
     fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.paths.is_empty()
     }
 }
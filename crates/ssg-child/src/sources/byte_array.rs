@@ -9,4 +9,8 @@ impl<const N: usize> FileSource for &[u8; N] {
     {
         async { Ok(FileContents::new(self.to_vec(), None)) }.boxed()
     }
+
+    fn source_type(&self) -> &'static str {
+        "static bytes"
+    }
 }
@@ -29,4 +29,8 @@ impl FileSource for BytesSource {
         let expected_files = Some(self.expected_files.clone());
         async { Ok(FileContents::new(bytes, expected_files)) }.boxed()
     }
+
+    fn source_type(&self) -> &'static str {
+        "bytes"
+    }
 }
@@ -15,4 +15,8 @@ impl FileSource for Path {
         .map_err(|error: std::io::Error| -> Box<dyn std::error::Error + Send> { Box::new(error) })
         .boxed()
     }
+
+    fn source_type(&self) -> &'static str {
+        "file"
+    }
 }
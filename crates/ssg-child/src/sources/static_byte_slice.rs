@@ -8,4 +8,8 @@ impl FileSource for &'static [u8] {
     ) -> BoxFuture<'static, Result<FileContents, Box<dyn std::error::Error + Send>>> {
         async { Ok(FileContents::new(self.to_vec(), None)) }.boxed()
     }
+
+    fn source_type(&self) -> &'static str {
+        "static bytes"
+    }
 }
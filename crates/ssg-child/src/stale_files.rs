@@ -0,0 +1,182 @@
+use std::{collections::BTreeSet, io::ErrorKind};
+
+use camino::{Utf8Path, Utf8PathBuf};
+use relative_path::RelativePathBuf;
+use tokio::fs;
+
+use crate::file_error::{FileError, FileErrorCause};
+
+type StaleFileFn = Box<dyn Fn(&RelativePathBuf) + 'static>;
+
+/// Removal of files in the output directory that no file spec produced,
+/// such as the pages of mobs that were deleted since a previous run
+pub struct StaleFileCleanup {
+    /// Files produced by other means than the file specs, which are kept
+    allowlist: BTreeSet<RelativePathBuf>,
+    /// Only reports the stale files
+    dry_run: bool,
+    stale_file_fn: Option<StaleFileFn>,
+}
+
+impl StaleFileCleanup {
+    pub fn new<P>(allowlist: impl IntoIterator<Item = P>, dry_run: bool) -> Self
+    where
+        RelativePathBuf: From<P>,
+    {
+        Self {
+            allowlist: allowlist
+                .into_iter()
+                .map(|path| RelativePathBuf::from(path).normalize())
+                .collect(),
+            dry_run,
+            stale_file_fn: None,
+        }
+    }
+
+    /// Called for each stale file, before it is removed
+    #[must_use]
+    pub fn with_stale_file_fn(
+        mut self,
+        stale_file_fn: impl Fn(&RelativePathBuf) + 'static,
+    ) -> Self {
+        self.stale_file_fn = Some(Box::new(stale_file_fn));
+        self
+    }
+
+    pub(crate) async fn run(
+        &self,
+        output_dir: &Utf8Path,
+        generated: &BTreeSet<RelativePathBuf>,
+    ) -> Vec<FileError> {
+        let files = match files(output_dir).await {
+            Ok(files) => files,
+            Err(error) => {
+                return vec![FileError::new(
+                    RelativePathBuf::new(),
+                    FileErrorCause::OutputIo(error),
+                )]
+            }
+        };
+
+        // file spec paths have a leading separator, which normalization removes
+        let generated = generated
+            .iter()
+            .map(|path| path.normalize())
+            .collect::<BTreeSet<_>>();
+
+        let mut errors = vec![];
+
+        for path in files {
+            if generated.contains(&path) || self.allowlist.contains(&path) {
+                continue;
+            }
+
+            // reported in the same form as file spec paths
+            let reported_path = RelativePathBuf::from(format!("/{path}"));
+
+            if let Some(stale_file_fn) = &self.stale_file_fn {
+                stale_file_fn(&reported_path);
+            }
+
+            if self.dry_run {
+                continue;
+            }
+
+            if let Err(error) = fs::remove_file(path.to_path(output_dir)).await {
+                errors.push(FileError::new(
+                    reported_path,
+                    FileErrorCause::OutputIo(error),
+                ));
+            }
+        }
+
+        errors
+    }
+}
+
+/// The paths of all the files in `output_dir` and its subdirectories, relative to it
+async fn files(output_dir: &Utf8Path) -> std::io::Result<BTreeSet<RelativePathBuf>> {
+    let mut files = BTreeSet::new();
+    let mut directories = vec![RelativePathBuf::new()];
+
+    while let Some(directory) = directories.pop() {
+        let mut entries = match fs::read_dir(directory.to_path(output_dir)).await {
+            Ok(entries) => entries,
+            Err(error) if error.kind() == ErrorKind::NotFound => continue,
+            Err(error) => return Err(error),
+        };
+
+        while let Some(entry) = entries.next_entry().await? {
+            let name = Utf8PathBuf::try_from(entry.path())
+                .map_err(|error| std::io::Error::new(ErrorKind::InvalidData, error))?;
+            let path = directory.join(name.file_name().unwrap());
+
+            if entry.file_type().await?.is_dir() {
+                directories.push(path);
+            } else {
+                files.insert(path);
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod test {
+    use std::{cell::RefCell, collections::BTreeSet, rc::Rc};
+
+    use camino::Utf8Path;
+    use relative_path::RelativePathBuf;
+
+    use super::StaleFileCleanup;
+
+    async fn run(dry_run: bool) -> (Vec<String>, tempfile::TempDir) {
+        let directory = tempfile::tempdir().unwrap();
+        let output_dir = Utf8Path::from_path(directory.path()).unwrap();
+
+        std::fs::create_dir(output_dir.join("mobs")).unwrap();
+
+        for path in ["index.html", "mobs/new.html", "mobs/old.html", "robots.txt"] {
+            std::fs::write(output_dir.join(path), path).unwrap();
+        }
+
+        let reported = Rc::new(RefCell::new(vec![]));
+        let reported_clone = reported.clone();
+
+        let cleanup = StaleFileCleanup::new(["/robots.txt"], dry_run)
+            .with_stale_file_fn(move |path| reported_clone.borrow_mut().push(path.to_string()));
+
+        let generated = BTreeSet::from([
+            RelativePathBuf::from("/index.html"),
+            RelativePathBuf::from("/mobs/new.html"),
+        ]);
+
+        let errors = cleanup.run(output_dir, &generated).await;
+
+        assert!(errors.is_empty());
+
+        let reported = reported.borrow().clone();
+        (reported, directory)
+    }
+
+    #[tokio::test]
+    async fn list() {
+        let (reported, directory) = run(true).await;
+
+        assert_eq!(reported, ["/mobs/old.html"]);
+        assert!(directory.path().join("mobs/old.html").exists());
+    }
+
+    #[tokio::test]
+    async fn remove() {
+        let (reported, directory) = run(false).await;
+
+        assert_eq!(reported, ["/mobs/old.html"]);
+        assert!(!directory.path().join("mobs/old.html").exists());
+
+        for path in ["index.html", "mobs/new.html", "robots.txt"] {
+            assert!(directory.path().join(path).exists());
+        }
+    }
+}
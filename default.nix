@@ -1,3 +1,4 @@
+{ self, ... }:
 {
   perSystem =
     { pkgs, config, ... }:
@@ -11,7 +12,8 @@
             ];
           }
           ''
-            builder ${./mobs} $out
+            builder build ${./mobs} $out --people ${./people.yaml} \
+              --last-modified ${toString self.lastModified}
           '';
     };
 }
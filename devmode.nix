@@ -14,6 +14,7 @@
               cargo run \
                 $(git rev-parse --show-toplevel)/mobs \
                 $(mktemp --directory) \
+                --people $(git rev-parse --show-toplevel)/people.yaml \
                 --open
             '';
           }